@@ -0,0 +1,158 @@
+//! Helpers for walking strings that may contain ANSI escape sequences.
+//!
+//! Escape sequences are recognized the same way [ansi_width] recognizes them, so
+//! widths computed from [Token]s always agree with the widths of [CountedString](super::CountedString).
+
+use super::{AnsiStyle, Color};
+use ansi_width::ansi_width;
+
+const ESC: char = '\x1b';
+const BEL: char = '\x07';
+
+/// A piece of a string: either a zero-width escape sequence or a single visible [char].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Token<'a> {
+    Escape(&'a str),
+    Char(char, &'a str)
+}
+
+impl<'a> Token<'a> {
+    pub fn str(self) -> &'a str {
+        match self {
+            Self::Escape(str) | Self::Char(_, str) => str
+        }
+    }
+
+    pub fn width(self) -> usize {
+        match self {
+            Self::Escape(_) => 0,
+            Self::Char(_, str) => ansi_width(str)
+        }
+    }
+}
+
+/// Returns whether the escape sequence is a Select Graphic Rendition (styling) sequence.
+pub(crate) fn is_sgr(escape: &str) -> bool {
+    escape.starts_with("\x1b[") && escape.ends_with('m')
+}
+
+/// Iterates over the [Tokens](Token) of a string.
+pub(crate) fn tokens(str: &str) -> Tokens<'_> {
+    Tokens { str, pos: 0 }
+}
+
+pub(crate) struct Tokens<'a> {
+    str: &'a str,
+    pos: usize
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let start = self.pos;
+        let mut chars = self.str[start..].chars();
+        let first = chars.next()?;
+
+        if first != ESC {
+            self.pos += first.len_utf8();
+            return Some(Token::Char(first, &self.str[start..self.pos]))
+        }
+
+        let mut end = start + ESC.len_utf8();
+        match chars.next() {
+            Some('\\') => end += 1,
+            // Control Sequence Introducer: continue until `\x40-\x7C`
+            Some('[') => {
+                end += 1;
+                for chr in chars.by_ref() {
+                    end += chr.len_utf8();
+                    if matches!(chr, '\x40'..='\x7C') {
+                        break
+                    }
+                }
+            },
+            // Operating System Command: continue until BEL or ST
+            Some(']') => {
+                end += 1;
+                let mut last = ']';
+                for chr in chars.by_ref() {
+                    end += chr.len_utf8();
+                    if chr == BEL || (chr == '\\' && last == ESC) {
+                        break
+                    }
+                    last = chr;
+                }
+            },
+            // Unknown sequence: only the ESC itself is zero-width
+            _ => {}
+        }
+
+        self.pos = end;
+        Some(Token::Escape(&self.str[start..end]))
+    }
+}
+
+/// Applies the effects of an SGR sequence to the style, as a terminal would.
+///
+/// Parameters that [AnsiStyle] can't represent are ignored. The default foreground and background
+/// (`39` and `49`) are kept as [Color::Default] so styles round-trip through [AnsiStrings](nu_ansi_term::AnsiStrings).
+pub(crate) fn apply_sgr(style: &mut AnsiStyle, escape: &str) {
+    let params = &escape[2..escape.len() - 1];
+    let mut codes = params.split(';').map(|code| match code {
+        "" => Some(0),
+        _ => code.parse::<u8>().ok()
+    });
+
+    while let Some(code) = codes.next() {
+        match code {
+            Some(0) => *style = AnsiStyle::default(),
+            Some(1) => style.is_bold = true,
+            Some(2) => style.is_dimmed = true,
+            Some(3) => style.is_italic = true,
+            Some(4) => style.is_underline = true,
+            Some(5 | 6) => style.is_blink = true,
+            Some(7) => style.is_reverse = true,
+            Some(8) => style.is_hidden = true,
+            Some(9) => style.is_strikethrough = true,
+            Some(22) => (style.is_bold, style.is_dimmed) = (false, false),
+            Some(23) => style.is_italic = false,
+            Some(24) => style.is_underline = false,
+            Some(25) => style.is_blink = false,
+            Some(27) => style.is_reverse = false,
+            Some(28) => style.is_hidden = false,
+            Some(29) => style.is_strikethrough = false,
+            Some(code @ 30..=37) => style.foreground = Some(BASIC_COLORS[(code - 30) as usize]),
+            Some(38) => if let Some(color) = extended_color(&mut codes) {
+                style.foreground = Some(color)
+            },
+            Some(39) => style.foreground = Some(Color::Default),
+            Some(code @ 40..=47) => style.background = Some(BASIC_COLORS[(code - 40) as usize]),
+            Some(48) => if let Some(color) = extended_color(&mut codes) {
+                style.background = Some(color)
+            },
+            Some(49) => style.background = Some(Color::Default),
+            Some(code @ 90..=97) => style.foreground = Some(BRIGHT_COLORS[(code - 90) as usize]),
+            Some(code @ 100..=107) => style.background = Some(BRIGHT_COLORS[(code - 100) as usize]),
+            _ => {}
+        }
+    }
+}
+
+const BASIC_COLORS: [Color; 8] = [
+    Color::Black, Color::Red, Color::Green, Color::Yellow,
+    Color::Blue, Color::Purple, Color::Cyan, Color::White
+];
+
+const BRIGHT_COLORS: [Color; 8] = [
+    Color::DarkGray, Color::LightRed, Color::LightGreen, Color::LightYellow,
+    Color::LightBlue, Color::LightPurple, Color::LightCyan, Color::LightGray
+];
+
+fn extended_color(codes: &mut impl Iterator<Item = Option<u8>>) -> Option<Color> {
+    match codes.next()? {
+        Some(5) => Some(Color::Fixed(codes.next()??)),
+        Some(2) => Some(Color::Rgb(codes.next()??, codes.next()??, codes.next()??)),
+        _ => None
+    }
+}
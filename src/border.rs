@@ -1,25 +1,28 @@
 /*! Defines the [BorderStyle] and related [BorderShape] type for describing
  * the look of a box's border. */
 
-use super::AnsiStyle;
+mod gradient;
+
+pub use gradient::{BorderGradient, GradientDirection};
+
+use super::{AnsiStyle, Color, Rgb, junction::{self, Weight}};
+use ansi_width::ansi_width;
+use std::{array, borrow::Cow, cmp};
 
-#[repr(usize)]
 #[derive(Debug, Clone, Copy)]
 pub(super) enum BorderChar {
-    TopLeft = 0,
-    TopRight = 1,
-    Side = 2,
-    BotLeft = 3,
-    BotRight = 4,
-    Edge = 5
-}
-
-impl BorderChar {
-    pub const NUM_BYTES: usize = BorderShape::SINGLE_SHAPES[0].len();
+    TopLeft,
+    TopRight,
+    Left,
+    Right,
+    BotLeft,
+    BotRight,
+    Top,
+    Bottom
 }
 
 /// Defines the shape of a [TermBox's](super::TermBox) border.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub enum BorderShape {
     /** Use a single line for the border: \
       * ┌─┐ \
@@ -34,29 +37,352 @@ pub enum BorderShape {
       * ╚═╝ \
       * Gaps displayed in documentation will not appear in most terminals, font-dependent.
       */
-    Double
-}
+    Double,
+
+    /** Use a single line with rounded corners for the border: \
+      * ╭─╮ \
+      * ╰─╯ \
+      * Gaps displayed in documentation will not appear in most terminals, font-dependent.
+      */
+    Rounded,
+
+    /** Use a single heavy (thick) line for the border: \
+      * ┏━┓ \
+      * ┗━┛ \
+      * Gaps displayed in documentation will not appear in most terminals, font-dependent.
+      */
+    Heavy,
+
+    /** Use a dashed single line for the border: \
+      * ┌╌┐ \
+      * ╎ ╎ \
+      * └╌┘
+      */
+    Dashed,
+
+    /** Use a dotted single line for the border: \
+      * ┌┈┐ \
+      * ┊ ┊ \
+      * └┈┘
+      */
+    Dotted,
+
+    /** Use only ASCII characters for the border, for output that may not support UTF-8: \
+      * +-+ \
+      * | | \
+      * +-+
+      */
+    Ascii,
+
+    /** Draw no border, but keep the space it would take up so text stays in the same place. */
+    Hidden,
 
-type Shapes = [&'static str; 6];
+    /** Use the given [BorderChars] for the border. See [BorderChars] for examples. */
+    Custom(BorderChars)
+}
 
 impl BorderShape {
-    const SINGLE_SHAPES: Shapes = [ "┌", "┐", "│", "└", "┘", "─" ];
-    const DOUBLE_SHAPES: Shapes = [ "╔", "╗", "║", "╚", "╝", "═" ];
+    /// Returns the [BorderChars] used to draw the shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use term_box::BorderShape;
+    ///
+    /// assert_eq!(BorderShape::Rounded.chars().top_left, "╭");
+    /// assert_eq!(BorderShape::Rounded.chars().left, "│");
+    /// ```
+    pub fn chars(&self) -> BorderChars {
+        match self {
+            Self::Single => BorderChars::uniform_corners("┌", "┐", "└", "┘", "─", "│"),
+            Self::Double => BorderChars::uniform_corners("╔", "╗", "╚", "╝", "═", "║"),
+            Self::Rounded => BorderChars::uniform_corners("╭", "╮", "╰", "╯", "─", "│"),
+            Self::Heavy => BorderChars::uniform_corners("┏", "┓", "┗", "┛", "━", "┃"),
+            Self::Dashed => BorderChars::uniform_corners("┌", "┐", "└", "┘", "╌", "╎"),
+            Self::Dotted => BorderChars::uniform_corners("┌", "┐", "└", "┘", "┈", "┊"),
+            Self::Ascii => BorderChars::uniform_corners("+", "+", "+", "+", "-", "|"),
+            Self::Hidden => BorderChars::uniform(" "),
+            Self::Custom(chars) => chars.clone()
+        }
+    }
 
-    pub(super) fn get_char(self, char: BorderChar) -> &'static str {
+    /// Returns the weight of the shape's lines, if it is drawn with solid box-drawing lines that
+    /// can be joined to other shapes.
+    pub(super) const fn weight(&self) -> Option<Weight> {
         match self {
-            Self::Single => Self::SINGLE_SHAPES[char as usize],
-            Self::Double => Self::DOUBLE_SHAPES[char as usize]
+            Self::Single | Self::Rounded | Self::Dashed | Self::Dotted => Some(Weight::Light),
+            Self::Heavy => Some(Weight::Heavy),
+            Self::Double => Some(Weight::Double),
+            Self::Ascii | Self::Hidden | Self::Custom(_) => None
         }
     }
+
+    /// Returns the glyph where a horizontal line of this shape crosses vertical lines of the `up` and
+    /// `down` shapes: the matching junction glyph where one exists, otherwise the shape's top lefthand
+    /// corner if it isn't drawn with solid lines, or the horizontal line itself.
+    pub(super) fn crossing(&self, up: Option<&BorderShape>, down: Option<&BorderShape>) -> Cow<'static, str> {
+        let chars = self.chars();
+        let Some(h) = self.weight() else {
+            return chars.top_left
+        };
+        let weight = |shape: Option<&BorderShape>| shape.map(BorderShape::weight);
+        match (weight(up), weight(down)) {
+            (Some(None), _) | (_, Some(None)) => chars.top,
+            (up, down) => junction::glyph([up.flatten(), Some(h), down.flatten(), Some(h)]).map_or(chars.top, Cow::Borrowed)
+        }
+    }
+
+    /// Returns the glyphs that join a top or bottom edge of this shape to the title in it, before
+    /// and after the title: the matching junction glyphs for solid lines, otherwise the shape's
+    /// righthand and lefthand sides.
+    pub(super) fn connectors(&self) -> (Cow<'static, str>, Cow<'static, str>) {
+        let chars = self.chars();
+        let Some(weight) = self.weight() else {
+            return (chars.right, chars.left)
+        };
+        let light = Some(Weight::Light);
+        let glyph = |arms, fallback| junction::glyph(arms).map_or(fallback, Cow::Borrowed);
+        (
+            glyph([light, None, light, Some(weight)], chars.right),
+            glyph([light, Some(weight), light, None], chars.left)
+        )
+    }
+}
+
+/// One of the four sides of a [TermBox's](super::TermBox) border.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// The top edge, which holds the [top title](super::Titles::top).
+    Top,
+    /// The righthand side.
+    Right,
+    /// The bottom edge, which holds the [bottom title](super::Titles::bottom).
+    Bottom,
+    /// The lefthand side.
+    Left
+}
+
+/// One of the four corners of a [TermBox's](super::TermBox) border.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Corner {
+    /// The top lefthand corner.
+    TopLeft,
+    /// The top righthand corner.
+    TopRight,
+    /// The bottom lefthand corner.
+    BottomLeft,
+    /// The bottom righthand corner.
+    BottomRight
+}
+
+/// The glyphs used to draw each part of a border.
+///
+/// Glyphs may be any string, including multi-byte and double-width characters, and are measured
+/// with the same ANSI-aware width used for the box's text. The lefthand corners and left side are
+/// padded with spaces on their inner side to the widest of the three, and likewise for the righthand
+/// glyphs, so the sides always line up. Top and bottom glyphs are repeated across the box, with
+/// spaces filling any columns too narrow for another glyph. Glyphs may be string literals, or
+/// [Strings](String) chosen at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use term_box::*;
+///
+/// let star = String::from("*");
+/// let stars = TermBox {
+///     border_style: BorderShape::Custom(BorderChars::uniform(star)).into(),
+///     padding: Padding::ONE_SPACE,
+///     lines: lines![ "starry" ],
+///     ..TermBox::default()
+/// };
+///
+/// let blocks = TermBox {
+///     border_style: BorderShape::Custom(BorderChars {
+///         top_left: "▛".into(), top: "▀".into(), top_right: "▜".into(),
+///         left: "▌".into(), right: "▐".into(),
+///         bottom_left: "▙".into(), bottom: "▄".into(), bottom_right: "▟".into()
+///     }).into(),
+///     lines: lines![ "blocky" ],
+///     ..TermBox::default()
+/// };
+///
+/// assert_eq!(stars.into_string(), "**********\n* starry *\n**********");
+/// assert_eq!(blocks.into_string(), "▛▀▀▀▀▀▀▜\n▌blocky▐\n▙▄▄▄▄▄▄▟");
+/// ```
+///
+/// Start from a built-in [BorderShape] and change some of its glyphs:
+///
+/// ```
+/// use term_box::*;
+///
+/// let chars = BorderChars { top: "━".into(), ..BorderShape::Single.chars() };
+/// let box_ = TermBox {
+///     border_style: BorderShape::Custom(chars).into(),
+///     lines: lines![ "heavy top" ],
+///     ..TermBox::default()
+/// };
+///
+/// assert_eq!(box_.into_string(), "┌━━━━━━━━━┐\n│heavy top│\n└─────────┘");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BorderChars {
+    /// The top lefthand corner.
+    pub top_left: Cow<'static, str>,
+    /// The glyph repeated across the top of the box.
+    pub top: Cow<'static, str>,
+    /// The top righthand corner.
+    pub top_right: Cow<'static, str>,
+    /// The glyph on the lefthand side of each line.
+    pub left: Cow<'static, str>,
+    /// The glyph on the righthand side of each line.
+    pub right: Cow<'static, str>,
+    /// The bottom lefthand corner.
+    pub bottom_left: Cow<'static, str>,
+    /// The glyph repeated across the bottom of the box.
+    pub bottom: Cow<'static, str>,
+    /// The bottom righthand corner.
+    pub bottom_right: Cow<'static, str>
+}
+
+impl BorderChars {
+    /// Creates [BorderChars] that use the same glyph for every part of the border.
+    pub fn uniform(glyph: impl Into<Cow<'static, str>>) -> Self {
+        let glyph = glyph.into();
+        Self::uniform_corners(glyph.clone(), glyph.clone(), glyph.clone(), glyph.clone(), glyph.clone(), glyph)
+    }
+
+    /// Creates [BorderChars] from four corners, one glyph for both horizontal edges and one glyph
+    /// for both vertical sides.
+    pub fn uniform_corners(
+        top_left: impl Into<Cow<'static, str>>,
+        top_right: impl Into<Cow<'static, str>>,
+        bottom_left: impl Into<Cow<'static, str>>,
+        bottom_right: impl Into<Cow<'static, str>>,
+        horizontal: impl Into<Cow<'static, str>>,
+        vertical: impl Into<Cow<'static, str>>
+    ) -> Self {
+        let (horizontal, vertical) = (horizontal.into(), vertical.into());
+        Self {
+            top_left: top_left.into(), top: horizontal.clone(), top_right: top_right.into(),
+            left: vertical.clone(), right: vertical,
+            bottom_left: bottom_left.into(), bottom: horizontal, bottom_right: bottom_right.into()
+        }
+    }
+
+    pub(super) fn get(&self, char: BorderChar) -> &str {
+        self.glyph(char)
+    }
+
+    /// Returns the glyph for `char` as it is stored, so it can be cloned without copying built-in glyphs.
+    pub(super) fn glyph(&self, char: BorderChar) -> &Cow<'static, str> {
+        match char {
+            BorderChar::TopLeft => &self.top_left,
+            BorderChar::TopRight => &self.top_right,
+            BorderChar::Left => &self.left,
+            BorderChar::Right => &self.right,
+            BorderChar::BotLeft => &self.bottom_left,
+            BorderChar::BotRight => &self.bottom_right,
+            BorderChar::Top => &self.top,
+            BorderChar::Bottom => &self.bottom
+        }
+    }
+
+    /// Returns the widths in columns of the lefthand and righthand sides of the border.
+    pub(super) fn side_widths(&self) -> (usize, usize) {
+        let width = |glyphs: [&str; 3]| glyphs.into_iter().map(ansi_width).fold(0, cmp::max);
+        (
+            width([ &self.top_left, &self.left, &self.bottom_left ]),
+            width([ &self.top_right, &self.right, &self.bottom_right ])
+        )
+    }
+}
+
+impl Default for BorderChars {
+    fn default() -> Self { BorderShape::default().chars() }
+}
+
+/// Pads the glyph with spaces to fill `width` columns, on the right if `left_side`, otherwise on the left.
+pub(super) fn pad_glyph(glyph: &str, width: usize, left_side: bool) -> String {
+    let spaces = " ".repeat(width.saturating_sub(ansi_width(glyph)));
+    match left_side {
+        true => format!("{glyph}{spaces}"),
+        false => format!("{spaces}{glyph}")
+    }
+}
+
+/// Repeats the glyph to fill `width` columns, filling any leftover columns with spaces.
+pub(super) fn fill_glyph(glyph: &str, width: usize) -> String {
+    match ansi_width(glyph) {
+        0 => " ".repeat(width),
+        glyph_width => glyph.repeat(width / glyph_width) + &" ".repeat(width % glyph_width)
+    }
 }
 
 /// Style for a [TermBox's](super::TermBox) border, determing the [shape](BorderStyle::shape)
 /// and [style](BorderStyle::ansi_style) of the border.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+///
+/// Each [Side] of the border may have its own [BorderShape], or not be drawn at all. Corners where two
+/// different line shapes meet are drawn with the matching junction glyph where one exists, and otherwise
+/// take the corner of the top or bottom edge. A side that isn't drawn takes up no space, along with its
+/// corners; the top or bottom row is still drawn if it has a [title](super::Titles).
+///
+/// # Examples
+///
+/// A double top edge over single sides, and an open quote bar:
+///
+/// ```
+/// use term_box::*;
+///
+/// let ledger = TermBox {
+///     border_style: BorderStyle::new_single().with_side(Side::Top, BorderShape::Double),
+///     lines: lines![ "ledger" ],
+///     ..TermBox::default()
+/// };
+///
+/// let quote = TermBox {
+///     border_style: BorderStyle::from(BorderShape::Heavy)
+///         .with_side(Side::Top, None)
+///         .with_side(Side::Right, None)
+///         .with_side(Side::Bottom, None),
+///     padding: Padding::ONE_SPACE,
+///     lines: lines![ "to be,", "or not" ],
+///     ..TermBox::default()
+/// };
+///
+/// assert_eq!(ledger.into_string(), "╒══════╕\n│ledger│\n└──────┘");
+/// assert_eq!(quote.into_string(), "┃ to be, \n┃ or not ");
+/// ```
+///
+/// Each side and corner may also be given its own [AnsiStyle], which is used instead of the
+/// [border's style](BorderStyle::with_style):
+///
+/// ```
+/// use term_box::*;
+///
+/// let status = TermBox {
+///     border_style: BorderStyle::new_single()
+///         .with_style(Color::DarkGray)
+///         .with_side_style(Side::Top, Color::LightGreen.bold())
+///         .with_corner_style(Corner::TopLeft, Color::LightGreen)
+///         .with_corner_style(Corner::TopRight, Color::LightGreen),
+///     lines: lines![ "healthy" ],
+///     ..TermBox::default()
+/// };
+///
+/// status.print();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BorderStyle {
-    pub(super) shape: BorderShape,
-    pub(super) ansi: AnsiStyle
+    /// The shape of each [Side], or [None] if it isn't drawn.
+    pub(super) sides: [Option<BorderShape>; 4],
+    pub(super) ansi: AnsiStyle,
+    /// The style of each [Side], if different from [ansi](BorderStyle::ansi).
+    pub(super) side_styles: [Option<AnsiStyle>; 4],
+    /// The style of each [Corner], if different from [ansi](BorderStyle::ansi).
+    pub(super) corner_styles: [Option<AnsiStyle>; 4],
+    /// The gradient that colors the border, replacing the foreground of every style.
+    pub(super) gradient: Option<BorderGradient>
 }
 
 impl BorderStyle {
@@ -64,32 +390,199 @@ impl BorderStyle {
     pub fn new_single() -> Self { Self::default() }
 
     /// Creates a new [BorderStyle] with [BorderShape::Double] and no ANSI styling.
-    pub fn new_double() -> Self {
-        Self { shape: BorderShape::Double, ansi: AnsiStyle::default() }
-    }
+    pub fn new_double() -> Self { Self::from(BorderShape::Double) }
 
     /** Sets the [AnsiStyle] for the border and returns it. \
       * Styling may not appear properly outside of a terminal.
+      *
+      * Sides and corners with a style of their own keep it.
       */
     pub fn with_style(mut self, style: impl Into<AnsiStyle>) -> Self {
         self.ansi = style.into();
         self
     }
 
-    pub(super) fn get_edge_string(&self) -> String {
-        let base = self.shape.get_char(BorderChar::Side);
-        self.ansi.paint(base).to_string()
+    /// Sets the [AnsiStyle] for one [Side] of the border, not including its corners, and returns it.
+    pub fn with_side_style(mut self, side: Side, style: impl Into<AnsiStyle>) -> Self {
+        self.side_styles[side as usize] = Some(style.into());
+        self
+    }
+
+    /// Sets the [AnsiStyle] for one [Corner] of the border and returns it.
+    pub fn with_corner_style(mut self, corner: Corner, style: impl Into<AnsiStyle>) -> Self {
+        self.corner_styles[corner as usize] = Some(style.into());
+        self
+    }
+
+    /// Sets a [BorderGradient] to color the border and returns it.
+    ///
+    /// The gradient replaces the foreground color of the border's styles, but keeps their other
+    /// attributes, such as bold or a background color.
+    pub fn with_gradient(mut self, gradient: BorderGradient) -> Self {
+        self.gradient = Some(gradient);
+        self
+    }
+
+    /// Sets the [BorderShape] of one [Side] of the border and returns it. Pass [None] to leave the
+    /// side out entirely.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use term_box::{BorderShape, BorderStyle, Side};
+    ///
+    /// let style = BorderStyle::new_double().with_side(Side::Left, None);
+    /// assert_eq!(style.side(Side::Left), None);
+    /// assert_eq!(style.side(Side::Right), Some(BorderShape::Double));
+    /// ```
+    pub fn with_side(mut self, side: Side, shape: impl Into<Option<BorderShape>>) -> Self {
+        self.sides[side as usize] = shape.into();
+        self
+    }
+
+    /// Returns the [BorderShape] of one [Side] of the border, or [None] if it isn't drawn.
+    pub fn side(&self, side: Side) -> Option<BorderShape> { self.sides[side as usize].clone() }
+
+    /// Returns the [BorderChars] used to draw the border, with each side and corner resolved from
+    /// the shapes of the sides it belongs to. Parts of the border that aren't drawn are empty.
+    pub(super) fn chars(&self) -> BorderChars {
+        let [top, right, bottom, left] = self.sides.each_ref().map(Option::as_ref);
+        let glyph = |shape: Option<&BorderShape>, char| shape.map_or(Cow::Borrowed(""), |shape| shape.chars().glyph(char).clone());
+        BorderChars {
+            top_left: corner(top, left, BorderChar::TopLeft),
+            top: glyph(top, BorderChar::Top),
+            top_right: corner(top, right, BorderChar::TopRight),
+            left: glyph(left, BorderChar::Left),
+            right: glyph(right, BorderChar::Right),
+            bottom_left: corner(bottom, left, BorderChar::BotLeft),
+            bottom: glyph(bottom, BorderChar::Bottom),
+            bottom_right: corner(bottom, right, BorderChar::BotRight)
+        }
     }
 
-    /// Returns the [BorderShape] for the border.
-    pub fn shape(&self) -> BorderShape { self.shape }
+    /// Returns the styled string for the lefthand or righthand [Side] of the line in row `y` of a
+    /// border that is `size` columns wide and rows tall, padded to `width` columns.
+    pub(super) fn get_side_string(&self, side: Side, y: usize, size: (usize, usize), width: usize) -> String {
+        let (char, x) = match side {
+            Side::Right => (BorderChar::Right, size.0 - width),
+            _ => (BorderChar::Left, 0)
+        };
+        let glyph = pad_glyph(self.chars().get(char), width, side != Side::Right);
+        match glyph.is_empty() {
+            true => glyph,
+            false => self.style_at(char, (x, y), size).paint(glyph).to_string()
+        }
+    }
+
+    /// Returns the glyphs that join a divider of the given shape to the lefthand and righthand
+    /// sides: the matching junction glyphs where they exist, otherwise the divider's own corners.
+    /// Sides that aren't drawn are empty.
+    pub(super) fn junctions(&self, shape: &BorderShape) -> (Cow<'static, str>, Cow<'static, str>) {
+        let h = shape.weight();
+        let junction = |side: Side, char| {
+            let Some(vertical) = &self.sides[side as usize] else {
+                return Cow::Borrowed("")
+            };
+            let v = vertical.weight();
+            let arms = match side {
+                Side::Left => [v, h, v, None],
+                _ => [v, None, v, h]
+            };
+            match (h, v) {
+                (Some(_), Some(_)) => junction::glyph(arms),
+                _ => None
+            }.map_or_else(|| shape.chars().glyph(char).clone(), Cow::Borrowed)
+        };
+        (junction(Side::Left, BorderChar::TopLeft), junction(Side::Right, BorderChar::TopRight))
+    }
+
+    /// Returns the [AnsiStyle] used to draw a part of the border at column `x` and row `y` of a border
+    /// that is `size` columns wide and rows tall, taking the [gradient](BorderStyle::with_gradient) into account.
+    pub(super) fn style_at(&self, char: BorderChar, pos: (usize, usize), size: (usize, usize)) -> AnsiStyle {
+        let style = self.style_of(char);
+        match &self.gradient {
+            Some(gradient) => {
+                let Rgb { r, g, b } = gradient.color_at(char, pos, size);
+                AnsiStyle { foreground: Some(Color::Rgb(r, g, b)), ..style }
+            },
+            None => style
+        }
+    }
+
+    /// Returns the [AnsiStyle] used to draw a part of the border.
+    pub(super) fn style_of(&self, char: BorderChar) -> AnsiStyle {
+        match char {
+            BorderChar::TopLeft => self.corner_style(Corner::TopLeft),
+            BorderChar::TopRight => self.corner_style(Corner::TopRight),
+            BorderChar::BotLeft => self.corner_style(Corner::BottomLeft),
+            BorderChar::BotRight => self.corner_style(Corner::BottomRight),
+            BorderChar::Top => self.side_style(Side::Top),
+            BorderChar::Right => self.side_style(Side::Right),
+            BorderChar::Bottom => self.side_style(Side::Bottom),
+            BorderChar::Left => self.side_style(Side::Left)
+        }
+    }
+
+    /// Returns the widths in columns of the lefthand and righthand sides of the border.
+    pub(super) fn side_widths(&self) -> (usize, usize) {
+        self.chars().side_widths()
+    }
+
+    /// Returns the [BorderShape] of the top edge of the border, or [BorderShape::Hidden] if it
+    /// isn't drawn. See [side](BorderStyle::side) for the other sides.
+    pub fn shape(&self) -> BorderShape { self.side(Side::Top).unwrap_or(BorderShape::Hidden) }
 
     /// Returns the [AnsiStyle] for the border.
     pub fn ansi_style(&self) -> AnsiStyle { self.ansi }
+
+    /// Returns the [BorderGradient] for the border, if it has one.
+    pub fn gradient(&self) -> Option<&BorderGradient> { self.gradient.as_ref() }
+
+    /// Returns the [AnsiStyle] for one [Side] of the border.
+    pub fn side_style(&self, side: Side) -> AnsiStyle {
+        self.side_styles[side as usize].unwrap_or(self.ansi)
+    }
+
+    /// Returns the [AnsiStyle] for one [Corner] of the border.
+    pub fn corner_style(&self, corner: Corner) -> AnsiStyle {
+        self.corner_styles[corner as usize].unwrap_or(self.ansi)
+    }
+}
+
+impl Default for BorderStyle {
+    fn default() -> Self { Self::from(BorderShape::default()) }
 }
 
 impl From<BorderShape> for BorderStyle {
     fn from(shape: BorderShape) -> Self {
-        Self { shape, ansi: AnsiStyle::default() }
+        Self {
+            sides: array::from_fn(|_| Some(shape.clone())),
+            ansi: AnsiStyle::default(),
+            side_styles: [None; 4],
+            corner_styles: [None; 4],
+            gradient: None
+        }
     }
 }
+
+/// Returns the glyph for the corner where the `horizontal` (top or bottom) side meets the `vertical` side.
+fn corner(horizontal: Option<&BorderShape>, vertical: Option<&BorderShape>, char: BorderChar) -> Cow<'static, str> {
+    let (Some(horizontal), Some(vertical)) = (horizontal, vertical) else {
+        return Cow::Borrowed("")
+    };
+    if horizontal == vertical {
+        return horizontal.chars().glyph(char).clone()
+    }
+
+    let (h, v) = (horizontal.weight(), vertical.weight());
+    let arms = match char {
+        BorderChar::TopLeft => [None, h, v, None],
+        BorderChar::TopRight => [None, None, v, h],
+        BorderChar::BotLeft => [v, h, None, None],
+        _ => [v, None, None, h]
+    };
+    match (h, v) {
+        (Some(_), Some(_)) => junction::glyph(arms),
+        _ => None
+    }.map_or_else(|| horizontal.chars().glyph(char).clone(), Cow::Borrowed)
+}
@@ -0,0 +1,107 @@
+use super::{BorderChar, Rgb};
+use std::cmp;
+
+/// Describes how the colors of a [BorderGradient] are laid out across the border.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GradientDirection {
+    /// Blend the colors clockwise around the outline of the box, starting from the top lefthand corner
+    /// and blending back into the first color before returning to it, so there is no seam.
+    #[default]
+    Around,
+    /// Blend the colors from the top of the box to the bottom.
+    Vertical,
+    /// Blend the colors from the left of the box to the right.
+    Horizontal
+}
+
+/// A truecolor gradient for a [TermBox's](crate::TermBox) border, set with
+/// [BorderStyle::with_gradient](super::BorderStyle::with_gradient).
+///
+/// Each border glyph is colored separately, blending evenly between the given colors in the
+/// gradient's [direction](GradientDirection). Titles keep their own colors.
+///
+/// # Examples
+///
+/// ```
+/// use term_box::*;
+///
+/// const COLORS: &[Rgb] = &[ Rgb::new(255, 0, 128), Rgb::new(0, 128, 255) ];
+///
+/// let box_ = TermBox {
+///     border_style: BorderStyle::from(BorderShape::Rounded)
+///         .with_gradient(BorderGradient::new(COLORS, GradientDirection::Vertical)),
+///     lines: lines![ "sunset" ],
+///     ..TermBox::default()
+/// };
+///
+/// box_.print();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BorderGradient {
+    colors: Vec<Rgb>,
+    direction: GradientDirection
+}
+
+impl BorderGradient {
+    /// Creates a new [BorderGradient] blending between the `colors` in the given `direction`.
+    ///
+    /// The colors can be an array, a slice or a [Vec], so they may be chosen at runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` is empty.
+    pub fn new(colors: impl Into<Vec<Rgb>>, direction: GradientDirection) -> Self {
+        let colors = colors.into();
+        assert!(!colors.is_empty(), "a gradient needs at least one color");
+        Self { colors, direction }
+    }
+
+    /// Returns the colors the gradient blends between.
+    pub fn colors(&self) -> &[Rgb] { &self.colors }
+
+    /// Returns the [GradientDirection] of the gradient.
+    pub const fn direction(&self) -> GradientDirection { self.direction }
+
+    /// Returns the color of the glyph for `char` at column `x` and row `y` of a border that is
+    /// `width` columns wide and `height` rows tall.
+    pub(super) fn color_at(&self, char: BorderChar, (x, y): (usize, usize), (width, height): (usize, usize)) -> Rgb {
+        let (right, bottom) = (width.saturating_sub(1), height.saturating_sub(1));
+        match self.direction {
+            GradientDirection::Vertical => self.blend(y, bottom, false),
+            GradientDirection::Horizontal => self.blend(x, right, false),
+            GradientDirection::Around => {
+                let pos = match char {
+                    BorderChar::TopLeft | BorderChar::Top | BorderChar::TopRight => x,
+                    BorderChar::Right => right + y,
+                    BorderChar::BotLeft | BorderChar::Bottom | BorderChar::BotRight => right + bottom + (right - x),
+                    BorderChar::Left => 2 * right + bottom + (bottom - y)
+                };
+                self.blend(pos, 2 * (right + bottom), true)
+            }
+        }
+    }
+
+    /// Blends the colors at `pos` out of `len`, wrapping back around to the first color if `cyclic`.
+    fn blend(&self, pos: usize, len: usize, cyclic: bool) -> Rgb {
+        let colors = &self.colors;
+        let segments = match cyclic {
+            true => colors.len(),
+            false => colors.len() - 1
+        };
+        if segments == 0 || len == 0 {
+            return colors[0]
+        }
+
+        let at = (pos as f32 / len as f32) * segments as f32;
+        let idx = cmp::min(at as usize, segments - 1);
+        let from = colors[idx];
+        let to = colors[(idx + 1) % colors.len()];
+        lerp(from, to, at - idx as f32)
+    }
+}
+
+/// Blends `t` of the way from `from` to `to`, rounding each channel to the nearest value.
+fn lerp(from: Rgb, to: Rgb, t: f32) -> Rgb {
+    let channel = |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * t).round() as u8;
+    Rgb::new(channel(from.r, to.r), channel(from.g, to.g), channel(from.b, to.b))
+}
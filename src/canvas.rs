@@ -0,0 +1,216 @@
+use super::{AnsiStyle, Connector, DrawnBox, Line, TermBox, junction};
+use ansi_width::ansi_width;
+use std::io;
+
+/// A single column of a [Canvas]: the grapheme displayed in it and its [AnsiStyle].
+///
+/// The column to the right of a wide character holds no text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cell {
+    text: String,
+    style: AnsiStyle
+}
+
+impl Cell {
+    fn blank() -> Self {
+        Self { text: String::from(" "), style: AnsiStyle::default() }
+    }
+
+    /// Returns the grapheme displayed in the cell, which is empty if the cell is covered by the
+    /// wide character to its left.
+    pub fn text(&self) -> &str { &self.text }
+
+    /// Returns the [AnsiStyle] the cell is displayed in.
+    pub fn style(&self) -> AnsiStyle { self.style }
+
+    fn is_wide(&self) -> bool { ansi_width(&self.text) > 1 }
+
+    fn is_continuation(&self) -> bool { self.text.is_empty() }
+}
+
+/// A grid of [Cells](Cell) that [TermBoxes](TermBox) and styled text can be drawn onto at any
+/// position, then printed all at once.
+///
+/// Each thing drawn replaces whatever was under it, including with the spaces inside boxes, except
+/// that where box-drawing glyphs are drawn over each other they are merged into the glyph where
+/// their lines meet. So the borders of overlapping boxes join up, with the lines of the box drawn
+/// last winning where both have a line in the same direction. Positions count columns and rows
+/// from the top lefthand corner, and the canvas grows to fit whatever is drawn onto it.
+///
+/// # Examples
+///
+/// ```
+/// use term_box::*;
+///
+/// let mut canvas = Canvas::new();
+/// canvas.draw_box(0, 0, TermBox::default().with_lines(lines![ "a box under", "another", "box" ]));
+/// canvas.draw_box(7, 3, TermBox { border_style: BorderStyle::new_double(), ..TermBox::default().with_lines(lines![ "over" ]) });
+/// canvas.draw_text(0, 5, Color::Blue.paint("label"));
+///
+/// let output = format!("
+/// ┌───────────┐
+/// │a box under│
+/// │another    │
+/// │box   ╔════╣
+/// └──────╫over╢
+/// {}  ╚════╝
+/// ", Color::Blue.paint("label"));
+///
+/// assert_eq!(canvas.into_string(), output.trim());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Canvas {
+    rows: Vec<Vec<Cell>>,
+    /// The boxes drawn so far, which connector labels are kept clear of.
+    boxes: Vec<DrawnBox>
+}
+
+impl Canvas {
+    /// Creates a new, empty canvas.
+    pub fn new() -> Self { Self::default() }
+
+    /// Returns the number of columns in the canvas.
+    pub fn width(&self) -> usize {
+        self.rows.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Returns the number of rows in the canvas.
+    pub fn height(&self) -> usize { self.rows.len() }
+
+    /// Returns the [Cell] at the given column and row, or [None] if nothing has been drawn there.
+    pub fn cell(&self, x: usize, y: usize) -> Option<&Cell> {
+        self.rows.get(y).and_then(|cells| cells.get(x))
+    }
+
+    /// Draws the box with its top lefthand corner at the given column and row, and returns where
+    /// it was drawn so that [Connectors](Connector) can be attached to it.
+    pub fn draw_box(&mut self, x: usize, y: usize, tbox: impl Into<TermBox>) -> DrawnBox {
+        let tbox = tbox.into();
+        let border_style = tbox.border_style.clone();
+        let rendered = tbox.into_string();
+        let size = (rendered.lines().next().map_or(0, ansi_width), rendered.lines().count());
+        self.draw_text(x, y, rendered);
+        let drawn = DrawnBox::new(x, y, size, &border_style);
+        self.boxes.push(drawn.clone());
+        drawn
+    }
+
+    /// Draws the connector, joining it to the borders of its boxes and any lines it crosses, and
+    /// returns whether it was drawn. Connectors that would pass above or to the left of the canvas
+    /// are not drawn at all, as the canvas can't grow in those directions.
+    pub fn draw_connector(&mut self, connector: &Connector) -> bool {
+        let to_usize = |(col, row): (isize, isize)| Some((usize::try_from(col).ok()?, usize::try_from(row).ok()?));
+        let route = connector.route();
+        let Some(cells) = route.iter().map(|&cell| to_usize(cell)).collect::<Option<Vec<_>>>() else {
+            return false
+        };
+        let weight = connector.weight();
+
+        // The borders are joined as if drawn over the connector, so the boxes keep their shape
+        for (cell, direction) in connector.joins() {
+            let (Some((col, row)), Some(weight)) = (to_usize(cell), weight) else { continue };
+            if let Some(cell) = self.cell_mut(col, row)
+                && let Some(glyph) = junction::arms(&cell.text).and_then(|arms| junction::join(direction.arm(weight), arms))
+            {
+                cell.text = glyph.to_string()
+            }
+        }
+
+        let style = connector.style();
+        for ((col, row), glyph) in cells.into_iter().zip(connector.glyphs(&route)) {
+            self.put(col, row, Cell { text: glyph.to_string(), style })
+        }
+
+        if let Some(label) = connector.label() {
+            let width = ansi_width(label);
+            let start = connector.label_starts(&route, width).into_iter()
+                .filter(|&start| !self.boxes.iter().any(|tbox| tbox.overlaps(start, width)))
+                .find_map(to_usize);
+            if let Some((x, y)) = start {
+                self.draw_text(x, y, label)
+            }
+        }
+        true
+    }
+
+    /// Draws the lines of `text`, which may contain ANSI styling, with its first column at `x` and
+    /// its first line at `y`.
+    pub fn draw_text(&mut self, x: usize, y: usize, text: impl ToString) {
+        let (col, row) = (x, y);
+        for (idx, line) in text.to_string().lines().enumerate() {
+            let mut col = col;
+            for span in Line::from(line).spans() {
+                for chr in span.text().chars() {
+                    let mut buf = [0; 4];
+                    let text = chr.encode_utf8(&mut buf);
+                    match ansi_width(text) {
+                        // Combining characters join the character before them
+                        0 => if let Some(cell) = col.checked_sub(1).and_then(|col| self.cell_mut(col, row + idx)) {
+                            cell.text.push(chr)
+                        },
+                        width => {
+                            self.put(col, row + idx, Cell { text: text.to_string(), style: span.style() });
+                            if width > 1 {
+                                self.put(col + 1, row + idx, Cell { text: String::new(), style: span.style() });
+                            }
+                            col += width
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Places the cell on the canvas, merging it with the glyph already there if both are box-drawing glyphs.
+    fn put(&mut self, col: usize, row: usize, mut cell: Cell) {
+        if self.rows.len() <= row {
+            self.rows.resize_with(row + 1, Vec::new);
+        }
+        let cells = &mut self.rows[row];
+        if cells.len() <= col {
+            cells.resize_with(col + 1, Cell::blank);
+        }
+
+        let old = &cells[col];
+        if let Some(merged) = junction::merge(&old.text, &cell.text) {
+            cell.text = merged.to_string()
+        }
+        // Wide characters that are partly covered are replaced with blanks
+        if old.is_continuation() && !cell.is_continuation() && col > 0 {
+            cells[col - 1] = Cell::blank()
+        }
+        if cells[col].is_wide() && cells.get(col + 1).is_some_and(Cell::is_continuation) && !cell.is_wide() {
+            cells[col + 1] = Cell::blank()
+        }
+        cells[col] = cell;
+    }
+
+    fn cell_mut(&mut self, col: usize, row: usize) -> Option<&mut Cell> {
+        self.rows.get_mut(row).and_then(|cells| cells.get_mut(col))
+    }
+
+    /// Prints the canvas to `write` with a final newline.
+    pub fn print_to<T: io::Write>(self, write: &mut T) -> io::Result<()> {
+        writeln!(write, "{}", self.into_string())
+    }
+
+    /// Prints the canvas to [stdout](io::stdout) with a final newline.
+    pub fn print(self) {
+        let _ = self.print_to(&mut io::stdout());
+    }
+
+    /// Converts the canvas to a [String] for display in the terminal, with every line padded to
+    /// the width of the widest. Columns nothing has been drawn in are left blank.
+    pub fn into_string(self) -> String {
+        let width = self.width();
+        let lines: Vec<_> = self.rows.into_iter()
+            .map(|mut cells| {
+                cells.resize_with(width, Cell::blank);
+                let mut line = Line::new();
+                cells.iter().for_each(|cell| line.push_styled(&cell.text, cell.style));
+                line.to_string()
+            })
+            .collect();
+        lines.join("\n")
+    }
+}
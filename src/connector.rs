@@ -0,0 +1,392 @@
+use super::{AnsiStyle, BorderShape, BorderStyle, Side, junction::{Arms, Weight}};
+use std::{borrow::Cow, cmp};
+
+/// A box drawn on a [Canvas](super::Canvas), returned by [draw_box](super::Canvas::draw_box) so that
+/// [Connectors](Connector) can be attached to its sides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DrawnBox {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    /// The shape and style of each [Side] of the box's border.
+    sides: [(Option<BorderShape>, AnsiStyle); 4]
+}
+
+impl DrawnBox {
+    pub(crate) fn new(x: usize, y: usize, (width, height): (usize, usize), border_style: &BorderStyle) -> Self {
+        let sides = [Side::Top, Side::Right, Side::Bottom, Side::Left]
+            .map(|side| (border_style.side(side), border_style.side_style(side)));
+        Self { x, y, width, height, sides }
+    }
+
+    /// Returns the column of the box's lefthand side.
+    pub fn x(&self) -> usize { self.x }
+
+    /// Returns the row of the box's top edge.
+    pub fn y(&self) -> usize { self.y }
+
+    /// Returns the width of the box in columns, including its border.
+    pub fn width(&self) -> usize { self.width }
+
+    /// Returns the height of the box in rows, including its border.
+    pub fn height(&self) -> usize { self.height }
+
+    /// Returns whether any of the `width` cells starting at the given column and row are inside the box.
+    pub(crate) fn overlaps(&self, (col, row): (isize, isize), width: usize) -> bool {
+        let (x, y) = (self.x as isize, self.y as isize);
+        (y..y + self.height as isize).contains(&row) && col < x + self.width as isize && x < col + width as isize
+    }
+
+    /// Returns the point in the middle of the given side, where connectors are attached.
+    fn port(&self, side: Side) -> Port {
+        let (x, y) = (self.x as isize, self.y as isize);
+        let (width, height) = (self.width as isize, self.height as isize);
+        let (col, row) = match side {
+            Side::Top => (x + width / 2, y),
+            Side::Right => (x + width - 1, y + height / 2),
+            Side::Bottom => (x + width / 2, y + height - 1),
+            Side::Left => (x, y + height / 2)
+        };
+        let (shape, style) = self.sides[side as usize].clone();
+        Port { col, row, side, shape, style }
+    }
+}
+
+/// A point on the border of a [DrawnBox] that a [Connector] is attached to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Port {
+    col: isize,
+    row: isize,
+    side: Side,
+    shape: Option<BorderShape>,
+    style: AnsiStyle
+}
+
+impl Port {
+    /// Returns the cell just outside the box, where the connector starts or ends.
+    fn outside(&self) -> (isize, isize) {
+        Direction::from(self.side).step((self.col, self.row))
+    }
+}
+
+/// A line drawn on a [Canvas](super::Canvas) from a side of one [DrawnBox] to a side of another,
+/// ending in an arrowhead.
+///
+/// Connectors leave and reach the middle of each side at right angles, turning corners where they
+/// need to: a connector between two lefthand or righthand sides, or two top or bottom edges, turns
+/// twice, halfway between the boxes or just past the further one if both sides face the same way,
+/// and one between a side and an edge turns once, or three times if it would otherwise reach the
+/// second box from the wrong direction. Routes are not
+/// planned around other boxes. Where a connector is attached to a box or crosses another line, the
+/// glyphs are joined like overlapping borders.
+///
+/// Connectors are drawn with the glyphs of the [BorderShape] of the side they leave, and in its
+/// style, unless given a shape or style of their own.
+///
+/// # Examples
+///
+/// ```
+/// use term_box::*;
+///
+/// let mut canvas = Canvas::new();
+/// let parse = canvas.draw_box(0, 0, TermBox::default().with_lines(lines![ "parse" ]));
+/// let check = canvas.draw_box(0, 4, TermBox { border_style: BorderStyle::new_double(), ..TermBox::default().with_lines(lines![ "check" ]) });
+/// let report = canvas.draw_box(14, 0, TermBox::default().with_lines(lines![ "", "", "report", "", "" ]));
+/// canvas.draw_connector(&Connector::new(&parse, Side::Bottom, &check, Side::Top).with_label("ok"));
+/// canvas.draw_connector(&Connector::new(&check, Side::Right, &report, Side::Left));
+///
+/// let output = "
+/// ┌─────┐       ┌──────┐
+/// │parse│       │      │
+/// └──┬──┘       │      │
+///    ▼ ok   ╔══▶│report│
+/// ╔═════╗   ║   │      │
+/// ║check╠═══╝   │      │
+/// ╚═════╝       └──────┘
+/// ";
+///
+/// assert_eq!(canvas.into_string(), output.trim());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Connector {
+    from: Port,
+    to: Port,
+    shape: Option<BorderShape>,
+    style: Option<AnsiStyle>,
+    label: Option<String>,
+    arrow: bool
+}
+
+impl Connector {
+    /// Creates a new connector from a side of one box to a side of another, with an arrowhead
+    /// pointing at the second box.
+    pub fn new(from: &DrawnBox, from_side: Side, to: &DrawnBox, to_side: Side) -> Self {
+        Self { from: from.port(from_side), to: to.port(to_side), shape: None, style: None, label: None, arrow: true }
+    }
+
+    /// Sets the [BorderShape] of the connector's line and returns it.
+    pub fn with_shape(self, shape: BorderShape) -> Self {
+        Self { shape: Some(shape), ..self }
+    }
+
+    /// Sets the [AnsiStyle] of the connector's line and returns it.
+    pub fn with_style(self, style: impl Into<AnsiStyle>) -> Self {
+        Self { style: Some(style.into()), ..self }
+    }
+
+    /// Sets the label displayed along the connector and returns it.
+    ///
+    /// The label is placed on the longest horizontal stretch of the line if it fits there, or
+    /// otherwise beside the middle of the line. It may cover text and other connectors, but not
+    /// the boxes drawn on the canvas, so if every place would cover one the label is left out.
+    pub fn with_label(self, label: impl ToString) -> Self {
+        Self { label: Some(label.to_string()), ..self }
+    }
+
+    /// Sets whether the connector ends in an arrowhead and returns it. Connectors without one
+    /// are joined to the second box like they are to the first.
+    pub fn with_arrow(self, arrow: bool) -> Self {
+        Self { arrow, ..self }
+    }
+
+    /// Returns the [BorderShape] of the connector's line: its own, or that of the side it leaves,
+    /// or [Single](BorderShape::Single) if that side isn't drawn.
+    pub fn shape(&self) -> BorderShape {
+        self.shape.clone().or_else(|| self.from.shape.clone()).unwrap_or_default()
+    }
+
+    /// Returns the [AnsiStyle] of the connector's line: its own, or that of the side it leaves.
+    pub fn style(&self) -> AnsiStyle {
+        self.style.unwrap_or(self.from.style)
+    }
+
+    /// Returns the label displayed along the connector, if it has one.
+    pub fn label(&self) -> Option<&str> { self.label.as_deref() }
+
+    /// Returns whether the connector ends in an arrowhead.
+    pub fn arrow(&self) -> bool { self.arrow }
+
+    /// Returns the cells the connector passes through, from just outside the first box to just
+    /// outside the second.
+    pub(crate) fn route(&self) -> Vec<(isize, isize)> {
+        let (start, end) = (self.from.outside(), self.to.outside());
+        let horizontal = |side| matches!(side, Side::Left | Side::Right);
+        // Connectors between sides facing the same way turn one step past the further box, and
+        // others turn halfway between the boxes
+        let turn = |start: isize, end: isize| match self.from.side {
+            side if side != self.to.side => (start + end).div_euclid(2),
+            side => beyond(start, end, side)
+        };
+        // Connectors that turn once must reach the second box moving toward it, or else they go
+        // around the side of it
+        let reaches = |start: isize, end: isize| outward(self.to.side) * (end - start) < 0;
+        let corners = match (horizontal(self.from.side), horizontal(self.to.side)) {
+            (true, true) => {
+                let col = turn(start.0, end.0);
+                vec![(col, start.1), (col, end.1)]
+            },
+            (false, false) => {
+                let row = turn(start.1, end.1);
+                vec![(start.0, row), (end.0, row)]
+            },
+            (true, false) if reaches(start.1, end.1) => vec![(end.0, start.1)],
+            (false, true) if reaches(start.0, end.0) => vec![(start.0, end.1)],
+            (true, false) => {
+                let (col, row) = ((start.0 + end.0).div_euclid(2), beyond(start.1, end.1, self.to.side));
+                vec![(col, start.1), (col, row), (end.0, row)]
+            },
+            (false, true) => {
+                let (col, row) = (beyond(start.0, end.0, self.to.side), (start.1 + end.1).div_euclid(2));
+                vec![(start.0, row), (col, row), (col, end.1)]
+            }
+        };
+
+        let mut cells = vec![start];
+        let (mut col, mut row) = start;
+        for (to_col, to_row) in corners.into_iter().chain([end]) {
+            while col != to_col {
+                col += (to_col - col).signum();
+                cells.push((col, row));
+            }
+            while row != to_row {
+                row += (to_row - row).signum();
+                cells.push((col, row));
+            }
+        }
+        cells
+    }
+
+    /// Returns the glyph for each cell of the [route](Connector::route), and the arrowhead.
+    pub(crate) fn glyphs(&self, route: &[(isize, isize)]) -> Vec<Cow<'static, str>> {
+        let into_from = Direction::from(self.from.side).opposite();
+        let into_to = Direction::from(self.to.side).opposite();
+
+        let mut glyphs: Vec<_> = route.iter().enumerate()
+            .map(|(idx, &cell)| {
+                let back = match idx {
+                    0 => into_from,
+                    _ => Direction::between(cell, route[idx - 1])
+                };
+                let ahead = route.get(idx + 1).map_or(into_to, |&next| Direction::between(cell, next));
+                self.line_glyph(back, ahead)
+            })
+            .collect();
+        if self.arrow && let Some(last) = glyphs.last_mut() {
+            *last = Cow::Borrowed(self.arrowhead(into_to));
+        }
+        glyphs
+    }
+
+    /// Returns the cells on the borders of the boxes that the connector is joined to, with the
+    /// direction the connector leaves them in.
+    pub(crate) fn joins(&self) -> Vec<((isize, isize), Direction)> {
+        let mut joins = vec![((self.from.col, self.from.row), Direction::from(self.from.side))];
+        if !self.arrow {
+            joins.push(((self.to.col, self.to.row), Direction::from(self.to.side)));
+        }
+        joins
+    }
+
+    /// Returns the places the label could start along the [route](Connector::route), given its
+    /// width, in order of preference: centered on the longest horizontal stretch between corners if
+    /// it fits, or else beside the middle cell, to the right or left of a vertical line or above or
+    /// below a horizontal one.
+    pub(crate) fn label_starts(&self, route: &[(isize, isize)], width: usize) -> Vec<(isize, isize)> {
+        let mut runs: Vec<&[(isize, isize)]> = route.chunk_by(|a, b| a.1 == b.1).collect();
+        runs.sort_by_key(|run| cmp::Reverse(run.len()));
+        let width = width as isize;
+        match runs.first() {
+            // The cells at either end of a stretch may hold a corner or the arrowhead
+            Some(run) if run.len() as isize - 2 >= width => {
+                let first = run.iter().map(|&(col, _)| col).min().unwrap_or_default() + 1;
+                vec![(first + (run.len() as isize - 2 - width) / 2, run[0].1)]
+            },
+            _ => {
+                let mid = route.len() / 2;
+                let Some(&(col, row)) = route.get(mid) else { return Vec::new() };
+                let vertical = route[mid.saturating_sub(1)..cmp::min(mid + 2, route.len())].iter().all(|cell| cell.0 == col);
+                match vertical {
+                    true => vec![(col + 2, row), (col - 1 - width, row)],
+                    false => vec![(col, row - 1), (col, row + 1)]
+                }
+            }
+        }
+    }
+
+    /// Returns the weight of the connector's line, if it can be joined to other lines.
+    pub(crate) fn weight(&self) -> Option<Weight> { self.shape().weight() }
+
+    /// Returns the glyph of a line through a cell, between the directions `back` and `ahead`.
+    fn line_glyph(&self, back: Direction, ahead: Direction) -> Cow<'static, str> {
+        use Direction::*;
+        let chars = self.shape().chars();
+        match (back, ahead) {
+            (Up, Down) | (Down, Up) => chars.left,
+            (Right, Left) | (Left, Right) => chars.top,
+            (Right, Down) | (Down, Right) => chars.top_left,
+            (Down, Left) | (Left, Down) => chars.top_right,
+            (Up, Right) | (Right, Up) => chars.bottom_left,
+            (Up, Left) | (Left, Up) => chars.bottom_right,
+            // The route doubles back on itself
+            (Up, Up) | (Down, Down) => chars.left,
+            (Right, Right) | (Left, Left) => chars.top
+        }
+    }
+
+    /// Returns the arrowhead pointing in the given direction.
+    fn arrowhead(&self, direction: Direction) -> &'static str {
+        let (ascii, solid) = match direction {
+            Direction::Up => ("^", "▲"),
+            Direction::Right => (">", "▶"),
+            Direction::Down => ("v", "▼"),
+            Direction::Left => ("<", "◀")
+        };
+        match self.shape() {
+            BorderShape::Ascii => ascii,
+            _ => solid
+        }
+    }
+}
+
+/// One of the four directions a connector moves in, or a line leaves a cell in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Direction {
+    Up,
+    Right,
+    Down,
+    Left
+}
+
+impl Direction {
+    /// Returns the direction of the single step from one cell to the next.
+    fn between(from: (isize, isize), to: (isize, isize)) -> Self {
+        match ((to.0 - from.0).signum(), (to.1 - from.1).signum()) {
+            (0, -1) => Self::Up,
+            (1, _) => Self::Right,
+            (0, 1) => Self::Down,
+            _ => Self::Left
+        }
+    }
+
+    /// Returns the cell one step from `cell` in this direction.
+    fn step(self, (col, row): (isize, isize)) -> (isize, isize) {
+        match self {
+            Self::Up => (col, row - 1),
+            Self::Right => (col + 1, row),
+            Self::Down => (col, row + 1),
+            Self::Left => (col - 1, row)
+        }
+    }
+
+    /// Returns the direction pointing the other way.
+    fn opposite(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right
+        }
+    }
+
+    /// Returns the [Arms] of a glyph with a single line of the given weight in this direction.
+    pub(crate) fn arm(self, weight: Weight) -> Arms {
+        let mut arms = [None; 4];
+        let idx = match self {
+            Self::Up => 0,
+            Self::Right => 1,
+            Self::Down => 2,
+            Self::Left => 3
+        };
+        arms[idx] = Some(weight);
+        arms
+    }
+}
+
+/// The direction the side faces, away from the box.
+impl From<Side> for Direction {
+    fn from(side: Side) -> Self {
+        match side {
+            Side::Top => Self::Up,
+            Side::Right => Self::Right,
+            Side::Bottom => Self::Down,
+            Side::Left => Self::Left
+        }
+    }
+}
+
+/// Returns 1 if the side faces right or down, or -1 if it faces left or up.
+fn outward(side: Side) -> isize {
+    match side {
+        Side::Top | Side::Left => -1,
+        Side::Right | Side::Bottom => 1
+    }
+}
+
+/// Returns the row or column one step past both `start` and `end` in the direction the side faces.
+fn beyond(start: isize, end: isize, side: Side) -> isize {
+    match outward(side) {
+        1 => start.max(end) + 1,
+        _ => start.min(end) - 1
+    }
+}
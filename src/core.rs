@@ -16,8 +16,22 @@ pub struct TermBox {
     pub padding: Padding,
     /// [Titles] for the box.
     pub titles: Titles,
-    /// Lines of text to display in the box.
-    pub lines: Vec<Line>
+    /// Lines of text to display in the box, which may include [Dividers](Divider) and other boxes.
+    pub lines: Vec<Line>,
+    /// The maximum width of the box in columns, including its border and [padding](TermBox::padding).
+    ///
+    /// Lines that would make the box wider are handled according to the box's
+    /// [overflow](TermBox::overflow). Titles are not affected and may still widen the box
+    /// past this width, depending on the box's [title_overflow](TermBox::title_overflow).
+    /// Neither are lines holding vertical lines, such as the rows of a [Table], which are kept
+    /// whole so their lines still join the border. [None] for no maximum.
+    pub max_width: Option<usize>,
+    /// [Overflow] describing how lines wider than the [max_width](TermBox::max_width) are fit.
+    pub overflow: Overflow,
+    /// The [Alignment] of lines that don't have an [alignment](Line::align) of their own.
+    pub align: Alignment,
+    /// [TitleOverflow] describing what happens to titles wider than the [max_width](TermBox::max_width).
+    pub title_overflow: TitleOverflow
 }
 
 impl TermBox {
@@ -42,7 +56,143 @@ impl TermBox {
         Self { lines, ..self }
     }
 
-    /// Appends an additional line to the box's contents.
+    /// Creates a new [TermBox] that is a copy of this box with the [max_width](TermBox::max_width)
+    /// set to `max_width` columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use term_box::{lines, TermBox};
+    ///
+    /// let box_ = TermBox::default()
+    ///     .with_lines(lines![ "the quick brown fox" ])
+    ///     .with_max_width(13);
+    ///
+    /// let output = "
+    /// ┌─────────┐
+    /// │the quick│
+    /// │brown fox│
+    /// └─────────┘
+    /// ";
+    ///
+    /// assert_eq!(box_.into_string(), output.trim());
+    /// ```
+    pub fn with_max_width(self, max_width: usize) -> Self {
+        Self { max_width: Some(max_width), ..self }
+    }
+
+    /// Creates a new [TermBox] that is a copy of this box with the [max_width](TermBox::max_width)
+    /// limited to the [width of the current terminal](terminal_width), and titles that would
+    /// [grow](TitleOverflow::Grow) the box [truncated](TitleOverflow::ELLIPSIS) instead.
+    ///
+    /// Boxes printed with [print](TermBox::print) or [print_to](TermBox::print_to) will then
+    /// not be wrapped by the terminal, which would break up their borders. If the box already has
+    /// a smaller [max_width](TermBox::max_width), it is kept. The terminal is found through stdout
+    /// and stderr, so a box printed to another writer is fit to them rather than to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use term_box::{terminal_width, TermBox};
+    ///
+    /// let box_ = TermBox::default()
+    ///     .append_with("word ".repeat(100))
+    ///     .fit_to_terminal();
+    ///
+    /// assert_eq!(box_.max_width, Some(terminal_width()));
+    /// box_.print();
+    /// ```
+    pub fn fit_to_terminal(self) -> Self {
+        let width = terminal_width();
+        let max_width = self.max_width.map_or(width, |max| cmp::min(max, width));
+        // Growing titles would widen the box past the terminal
+        let title_overflow = match self.title_overflow {
+            TitleOverflow::Grow => TitleOverflow::ELLIPSIS,
+            title_overflow => title_overflow
+        };
+        Self { max_width: Some(max_width), title_overflow, ..self }
+    }
+
+    /// Creates a new [TermBox] that is a copy of this box with the [overflow](TermBox::overflow)
+    /// replaced by the passed `overflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use term_box::{lines, Overflow, TermBox};
+    ///
+    /// let box_ = TermBox::default()
+    ///     .with_lines(lines![ "the quick brown fox" ])
+    ///     .with_max_width(13)
+    ///     .with_overflow(Overflow::Truncate("...".into()));
+    ///
+    /// let output = "
+    /// ┌───────────┐
+    /// │the quic...│
+    /// └───────────┘
+    /// ";
+    ///
+    /// assert_eq!(box_.into_string(), output.trim());
+    /// ```
+    pub fn with_overflow(self, overflow: Overflow) -> Self {
+        Self { overflow, ..self }
+    }
+
+    /// Creates a new [TermBox] that is a copy of this box with the default [align](TermBox::align)
+    /// for its lines replaced by the passed `align`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use term_box::{lines, Alignment, Line, TermBox};
+    ///
+    /// let box_ = TermBox::default()
+    ///     .with_lines(lines![ "12", "345", Line::from("6").aligned(Alignment::Left) ])
+    ///     .with_align(Alignment::Right);
+    ///
+    /// let output = "
+    /// ┌───┐
+    /// │ 12│
+    /// │345│
+    /// │6  │
+    /// └───┘
+    /// ";
+    ///
+    /// assert_eq!(box_.into_string(), output.trim());
+    /// ```
+    pub fn with_align(self, align: Alignment) -> Self {
+        Self { align, ..self }
+    }
+
+    /// Creates a new [TermBox] that is a copy of this box with the [title_overflow](TermBox::title_overflow)
+    /// replaced by the passed `title_overflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use term_box::{lines, Title, TitleOverflow, TitlePosition, Titles, TermBox};
+    ///
+    /// let box_ = TermBox {
+    ///     titles: Titles::none().with_top(Title("a long, dynamic title", TitlePosition::Left)),
+    ///     max_width: Some(13),
+    ///     ..TermBox::default()
+    /// };
+    ///
+    /// let output = "
+    /// ┌a long, dy…┐
+    /// │fixed width│
+    /// └───────────┘
+    /// ";
+    ///
+    /// let box_ = box_.with_lines(lines![ "fixed width" ]).with_title_overflow(TitleOverflow::ELLIPSIS);
+    /// assert_eq!(box_.into_string(), output.trim());
+    /// ```
+    pub fn with_title_overflow(self, title_overflow: TitleOverflow) -> Self {
+        Self { title_overflow, ..self }
+    }
+
+    /// Appends an additional line to the box's contents, which can be anything that converts into a
+    /// [Line]: text, including painted text and numbers, a [Divider] or a nested box.
     ///
     /// # Examples
     ///
@@ -59,11 +209,12 @@ impl TermBox {
     /// let push_box = TermBox::default().with_lines(lines);
     /// assert_eq!(append_box, push_box);
     /// ```
-    pub fn append(&mut self, line: impl ToString) {
-        self.lines.push(line.to_string());
+    pub fn append(&mut self, line: impl Into<Line>) {
+        self.lines.push(line.into());
     }
 
-    /// Appends an additional line to the owned box's contents an returns the box.
+    /// Appends an additional line to the owned box's contents an returns the box. See
+    /// [append](TermBox::append).
     ///
     /// # Examples
     ///
@@ -79,7 +230,7 @@ impl TermBox {
     /// let push_box = TermBox::default().with_lines(lines);
     /// assert_eq!(append_box.append_with(WORLD), push_box);
     /// ```
-    pub fn append_with(mut self, line: impl ToString) -> Self {
+    pub fn append_with(mut self, line: impl Into<Line>) -> Self {
         self.append(line);
         self
     }
@@ -104,7 +255,8 @@ impl TermBox {
 
     /// Writes the box to the file or other [io::Write] implementor WITH a final newline.\
     /// If the implementor is not connected to a terminal, ANSI styles may not display
-    /// properly.
+    /// properly. The box is not fit to the implementor's width; see
+    /// [fit_to_terminal](TermBox::fit_to_terminal).
     ///
     /// # Examples
     ///
@@ -139,34 +291,126 @@ impl TermBox {
 
     /// Converts the box to a [String] for display in the terminal.
     pub fn into_string(self) -> String {
+        self.into_string_widened(0)
+    }
+
+    /// Converts the box to a [String] like [into_string](TermBox::into_string), with `extra` more
+    /// columns inside it than it needs.
+    pub(crate) fn into_string_widened(self, extra: usize) -> String {
         let mut lines = Vec::with_capacity(self.lines.len());
-        let mut longest_line: &CountedString = cmp::max(&self.titles.top.text, &self.titles.bottom.text);
+        let mut text_width = 0;
         if let Some(longest_idx) = self.map_to_counts_and_find_longest(&mut lines) {
-            longest_line = cmp::max(longest_line, &lines[longest_idx]);
+            text_width = lines[longest_idx].text.width;
         }
+        let padding = self.padding;
+        let titles = self.titles.clone().connected(&self.border_style);
+        let label_width = self.lines.iter()
+            .filter_map(Line::divider)
+            .map(|divider| divider.min_width(&self.border_style))
+            .fold(0, cmp::max);
+        let title_width = cmp::max(titles.min_width(), label_width);
+        let title_width = match (&self.title_overflow, self.max_text_width()) {
+            (TitleOverflow::Grow, _) | (_, None) => title_width,
+            // Other titles widen the box up to its max_width, and only overflow past that
+            (_, Some(max)) => cmp::min(title_width.saturating_sub(padding.horizontal()), max)
+        };
+        text_width = cmp::max(text_width, title_width);
+
+        let line_len = cmp::max(Self::MIN_LINE_LEN, format::line_len(text_width, padding.horizontal())) + extra;
+        let inner_len = line_len - Self::SIDES;
+        let text_len = cmp::max(1, inner_len - padding.horizontal());
+        let (top, header) = self.title_overflow.fit(&titles.top, inner_len, text_len);
+        let (bottom, footer) = self.title_overflow.fit(&titles.bottom, inner_len, text_len);
+        let titles = Titles { top, bottom, ..titles };
+
+        let to_row = |line: Line| format::Row {
+            align: line.align().unwrap_or(self.align),
+            text: CountedString::owned(line.to_string()),
+            divider: None,
+            labels: Vec::new(),
+            crossings: &[]
+        };
+        // Divider labels overflow like the titles, with wrapped labels in rows below the divider
+        let mut lines: Vec<_> = lines.into_iter().flat_map(|row| match row.divider {
+            Some(divider) => {
+                let (labels, wrapped) = self.title_overflow.fit(&divider.labels_in(&self.border_style), inner_len, text_len);
+                let mut rows = vec![format::Row { labels, ..row }];
+                rows.extend(wrapped.into_iter().map(to_row));
+                rows
+            },
+            None => vec![row]
+        }).collect();
+        lines.splice(0..0, header.into_iter().map(to_row));
+        lines.extend(footer.into_iter().map(to_row));
 
-        let line_len = cmp::max(Self::MIN_LINE_LEN, format::line_len(longest_line, self.padding.count()));
-        let mut buf = String::with_capacity((lines.len() + 2) * line_len);
+        let num_rows = lines.len() + padding.top() + padding.bottom();
+        // Blank rows are added below the lines if the side titles need more room
+        let extra_rows = self.titles.min_height().saturating_sub(num_rows);
+        let mut buf = String::with_capacity((num_rows + extra_rows + 2) * line_len);
 
-        format::make_top_line(&mut buf, &self, line_len);
+        let frame = format::Frame::new(&self, titles, line_len, num_rows + extra_rows);
+        let pad_strings = &padding.into_counted_strings();
+        // Edges and dividers join to the vertical lines in the rows right next to them
+        let crossings: Vec<_> = lines.iter().map(|line| format::row_crossings(line, pad_strings, line_len)).collect();
+        let next_to_edge = |crossings: Option<&format::Crossings>, pad| match pad {
+            0 => crossings.cloned().unwrap_or_default(),
+            _ => Vec::new()
+        };
+        format::make_top_line(&mut buf, &self, line_len, &frame, &next_to_edge(crossings.first(), padding.top()));
 
-        let edge_string = &self.border_style.get_edge_string();
-        let pad_string = &self.padding.into_counted_string();
-        for line in lines.iter() {
-            format::make_line(&mut buf, edge_string, pad_string, line, line_len)
+        let side_strings = |row| frame.side_strings(&self.border_style, row);
+        for row in 0..padding.top() {
+            format::make_blank_line(&mut buf, &side_strings(row), line_len)
         }
 
-        format::make_bottom_line(&mut buf, &self, line_len);
+        for (idx, line) in lines.iter().enumerate() {
+            let row = padding.top() + idx;
+            match line.divider {
+                Some(divider) => {
+                    let above = idx.checked_sub(1).map_or(&[][..], |above| &crossings[above]);
+                    let below = crossings.get(idx + 1).map_or(&[][..], Vec::as_slice);
+                    format::make_divider(&mut buf, &self, (divider, &line.labels), (line_len, row), &frame, (above, below))
+                },
+                None => format::make_line(&mut buf, &side_strings(row), pad_strings, line, line_len)
+            }
+        }
+
+        for row in 0..padding.bottom() + extra_rows {
+            format::make_blank_line(&mut buf, &side_strings(padding.top() + lines.len() + row), line_len)
+        }
+
+        let above_bottom = next_to_edge(crossings.last(), padding.bottom() + extra_rows);
+        format::make_bottom_line(&mut buf, &self, line_len, &frame, &above_bottom);
 
         buf
     }
 
-    fn map_to_counts_and_find_longest<'a>(&'a self, lines: &mut Vec<CountedString<'a>>) -> Option<usize> {
-        let mut max_idx = None;
+    fn map_to_counts_and_find_longest<'a>(&'a self, lines: &mut Vec<format::Row<'a>>) -> Option<usize> {
+        let mut max_idx: Option<usize> = None;
+
+        let max_text_width = self.max_text_width();
+        let rows = self.lines.iter().flat_map(|line| {
+            let align = line.align().unwrap_or(self.align);
+            let width = line.width();
+            let counted = match (line.nested(), max_text_width) {
+                (Some(nested), max_width) => Self::nested_lines(nested, max_width),
+                (None, Some(max)) if width > max && line.crossings().is_empty() => {
+                    self.overflow.fit(line, max).iter().map(|line| CountedString::owned(line.to_string())).collect()
+                },
+                _ => vec![CountedString::counted(line.to_string(), width)]
+            };
+            let divider = line.divider();
+            // Lines split up by the overflow no longer line up with the vertical lines around them
+            let crossings = match counted.len() {
+                1 => line.crossings(),
+                _ => &[]
+            };
+            counted.into_iter().map(move |text| format::Row { text, align, divider, labels: Vec::new(), crossings })
+        });
 
-        for (idx, line) in self.lines.iter().map(CountedString::new).enumerate() {
+        for (idx, line) in rows.enumerate() {
             match max_idx {
-                Some(max) if line > lines[max] => max_idx = Some(idx),
+                Some(max) if line.text > lines[max].text => max_idx = Some(idx),
                 None => max_idx = Some(idx),
                 _ => {}
             }
@@ -175,4 +419,30 @@ impl TermBox {
 
         max_idx
     }
+
+    /// Returns the lines of a box nested inside this one, fit within the widest its lines may be.
+    fn nested_lines(nested: &TermBox, max_width: Option<usize>) -> Vec<CountedString<'static>> {
+        let max_width = match (nested.max_width, max_width) {
+            (Some(nested_max), Some(max)) => Some(cmp::min(nested_max, max)),
+            (nested_max, max) => nested_max.or(max)
+        };
+        let nested = TermBox { max_width, ..nested.clone() };
+        nested.into_string().lines().map(|line| CountedString::owned(line.to_string())).collect()
+    }
+
+    /// Returns the widths in columns of the lefthand and righthand sides of the box, which are
+    /// widened to fit any characters of the side titles that are wider than the border.
+    fn side_widths(&self) -> (usize, usize) {
+        let (border_left, border_right) = self.border_style.side_widths();
+        let (title_left, title_right) = self.titles.side_widths();
+        (cmp::max(border_left, title_left), cmp::max(border_right, title_right))
+    }
+
+    fn max_text_width(&self) -> Option<usize> {
+        self.max_width.map(|max| {
+            let (left_width, right_width) = self.side_widths();
+            let used = left_width + right_width + self.padding.horizontal();
+            cmp::max(1, max.saturating_sub(used))
+        })
+    }
 }
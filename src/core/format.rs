@@ -1,106 +1,393 @@
 use super::*;
+use ansi_width::ansi_width;
+use std::borrow::Cow;
 
-pub(super) fn line_len(line: &CountedString, padding: usize) -> usize {
-    line.width + TermBox::SIDES + (TermBox::SIDES * padding)
+/// Returns the full width of a line of the box with text `width` columns wide, where `padding`
+/// is the total horizontal padding.
+pub(super) fn line_len(width: usize, padding: usize) -> usize {
+    width + TermBox::SIDES + padding
+}
+
+/// A line of text as it is displayed in the box, after [overflow](TermBox::overflow) is applied.
+pub(super) struct Row<'a> {
+    pub text: CountedString<'a>,
+    pub align: Alignment,
+    /// The [Divider] displayed instead of the text, if the line is one.
+    pub divider: Option<&'a Divider>,
+    /// The divider's labels, after the box's [title_overflow](TermBox::title_overflow) is applied.
+    pub labels: Vec<Title>,
+    /// The columns of the text holding vertical lines, and their shapes.
+    pub crossings: &'a [(usize, BorderShape)]
+}
+
+/// A column of a row holding a vertical line, counting from the inside of the lefthand side, and
+/// the line's shape.
+pub(super) type Crossing = (usize, BorderShape);
+
+/// All of the [Crossings](Crossing) in a row.
+pub(super) type Crossings = Vec<Crossing>;
+
+/// Returns the [Crossings] of a row in a box with lines `min_len` long.
+pub(super) fn row_crossings(row: &Row, (left_pad, right_pad): &PadStrings, min_len: usize) -> Crossings {
+    let diff = min_len - line_len(row.text.width, left_pad.width + right_pad.width);
+    let start = left_pad.width + row.align.left_space(diff);
+    row.crossings.iter()
+        .filter(|&&(col, _)| col < row.text.width)
+        .map(|(col, shape)| (start + col, shape.clone()))
+        .collect()
+}
+
+/// Returns the glyphs where a horizontal line of the `edge` shape meets the vertical lines `above`
+/// and `below` it, in order. Edges that aren't drawn have none.
+fn crossing_glyphs(edge: Option<&BorderShape>, above: &[Crossing], below: &[Crossing]) -> Vec<(usize, Cow<'static, str>)> {
+    let Some(edge) = edge else {
+        return Vec::new()
+    };
+    let mut cols: Vec<_> = above.iter().chain(below).map(|&(col, _)| col).collect();
+    cols.sort();
+    cols.dedup();
+    fn shape_at(crossings: &[Crossing], col: usize) -> Option<&BorderShape> {
+        crossings.iter().find(|&&(at, _)| at == col).map(|(_, shape)| shape)
+    }
+    cols.into_iter().map(|col| (col, edge.crossing(shape_at(above, col), shape_at(below, col)))).collect()
+}
+
+/// The left and right [padding](TermBox::padding) strings.
+pub(super) type PadStrings = (CountedString<'static>, CountedString<'static>);
+
+/// The styled strings for the lefthand and righthand sides of a line.
+pub(super) type SideStrings = (String, String);
+
+/// The dimensions of a box's border, and the titles on its lefthand and righthand sides.
+pub(super) struct Frame {
+    /// The widths in columns of the lefthand and righthand sides.
+    pub side_widths: (usize, usize),
+    /// The width in columns and height in rows of the whole box.
+    pub size: (usize, usize),
+    /// The number of rows above the first line: 1 if the top edge is drawn, otherwise 0.
+    top_rows: usize,
+    /// The number of rows below the last line: 1 if the bottom edge is drawn, otherwise 0.
+    bottom_rows: usize,
+    /// The titles to display, after the box's [title_overflow](TermBox::title_overflow) is applied.
+    titles: Titles,
+    left_titles: Vec<Option<Span>>,
+    right_titles: Vec<Option<Span>>
+}
+
+impl Frame {
+    /// Creates the [Frame] for a box with lines `len` long and `rows` rows between the top and bottom
+    /// edges, displaying the given `titles` in place of the box's own.
+    pub fn new(tbox: &TermBox, titles: Titles, len: usize, rows: usize) -> Self {
+        let side_widths = tbox.side_widths();
+        let top_rows = draws_edge(&tbox.border_style, &titles.top, Side::Top) as usize;
+        let bottom_rows = draws_edge(&tbox.border_style, &titles.bottom, Side::Bottom) as usize;
+        Self {
+            side_widths,
+            size: (side_widths.0 + len - TermBox::SIDES + side_widths.1, top_rows + rows + bottom_rows),
+            top_rows,
+            bottom_rows,
+            left_titles: title::layout_vertical(&titles.left, rows),
+            right_titles: title::layout_vertical(&titles.right, rows),
+            titles
+        }
+    }
+
+    /// Returns the styled strings for the lefthand and righthand sides of the `row`th line below the top edge.
+    pub fn side_strings(&self, style: &BorderStyle, row: usize) -> SideStrings {
+        let (left_title, right_title) = self.side_titles(row);
+        (
+            left_title.unwrap_or_else(|| style.get_side_string(Side::Left, self.top_rows + row, self.size, self.side_widths.0)),
+            right_title.unwrap_or_else(|| style.get_side_string(Side::Right, self.top_rows + row, self.size, self.side_widths.1))
+        )
+    }
+
+    /// Returns the styled characters of the lefthand and righthand titles in the `row`th line below
+    /// the top edge, padded to the width of their sides, if there are any.
+    fn side_titles(&self, row: usize) -> (Option<String>, Option<String>) {
+        let cell = |titles: &[Option<Span>], width, left_side| titles[row].as_ref().map(|span| {
+            let text = border::pad_glyph(span.text(), width, left_side);
+            paint(&span.style(), text)
+        });
+        (
+            cell(&self.left_titles, self.side_widths.0, true),
+            cell(&self.right_titles, self.side_widths.1, false)
+        )
+    }
 }
 
 pub(super) fn make_line(
     buf: &mut String,
-    edge_string: &str,
-    pad_string: &CountedString,
-    text: &CountedString,
+    (left_side, right_side): &SideStrings,
+    (left_pad, right_pad): &PadStrings,
+    row: &Row,
     min_len: usize
 ) {
-    buf.push_str(edge_string);
-    buf.push_str(pad_string.str());
+    let text = &row.text;
+    let diff = min_len - line_len(text.width, left_pad.width + right_pad.width);
+    let left_diff = row.align.left_space(diff);
+
+    buf.push_str(left_side);
+    buf.push_str(left_pad.str());
+    if left_diff > 0 {
+        buf.push_str(&str::repeat(" ", left_diff))
+    }
+
     buf.push_str(text.str());
-    
-    let diff = min_len - line_len(text, pad_string.width);
-    if diff > 0 {
-        buf.push_str(&str::repeat(" ", diff))
+    if diff > left_diff {
+        buf.push_str(&str::repeat(" ", diff - left_diff))
     }
 
-    buf.push_str(pad_string.str());
-    buf.push_str(edge_string);
+    buf.push_str(right_pad.str());
+    buf.push_str(right_side);
+    buf.push('\n')
+}
+
+/// Makes a row of [vertical padding](Padding::top).
+pub(super) fn make_blank_line(buf: &mut String, (left_side, right_side): &SideStrings, len: usize) {
+    buf.push_str(left_side);
+    buf.push_str(&str::repeat(" ", len - TermBox::SIDES));
+    buf.push_str(right_side);
     buf.push('\n')
 }
 
 struct HorizLineArgs<'a> {
     len: usize,
-    style: BorderStyle,
-    title: &'a Title,
+    /// The row of the line within the whole box.
+    row: usize,
+    frame: &'a Frame,
+    style: &'a BorderStyle,
+    chars: BorderChars,
+    titles: &'a [Title],
+    /// Titles on the lefthand and righthand sides to display instead of the `left` and `right` glyphs.
+    side_titles: (Option<String>, Option<String>),
+    /// The glyphs to display where the line meets vertical lines inside the box, by column.
+    crossings: Vec<(usize, Cow<'static, str>)>,
     left: BorderChar,
+    edge: BorderChar,
     right: BorderChar
 }
 
-pub(super) fn make_top_line(buf: &mut String, tbox: &TermBox, len: usize) {
+pub(super) fn make_top_line(buf: &mut String, tbox: &TermBox, len: usize, frame: &Frame, below: &[Crossing]) {
+    if frame.top_rows == 0 {
+        return
+    }
+
     make_top_or_bottom_line(buf, HorizLineArgs {
-        len,
-        style: tbox.border_style, title: &tbox.titles.top,
-        left: BorderChar::TopLeft, right: BorderChar::TopRight
+        len, row: 0, frame,
+        style: &tbox.border_style, chars: tbox.border_style.chars(),
+        titles: &frame.titles.top, side_titles: (None, None),
+        crossings: crossing_glyphs(tbox.border_style.side(Side::Top).as_ref(), &[], below),
+        left: BorderChar::TopLeft, edge: BorderChar::Top, right: BorderChar::TopRight
     });
     buf.push('\n')
 }
 
-pub(super) fn make_bottom_line(buf: &mut String, tbox: &TermBox, len: usize) {
+pub(super) fn make_bottom_line(buf: &mut String, tbox: &TermBox, len: usize, frame: &Frame, above: &[Crossing]) {
+    if frame.bottom_rows == 0 {
+        // No final newline, as with a bottom edge
+        buf.pop();
+        return
+    }
+
     make_top_or_bottom_line(buf, HorizLineArgs {
-        len,
-        style: tbox.border_style, title: &tbox.titles.bottom,
-        left: BorderChar::BotLeft, right: BorderChar::BotRight
+        len, row: frame.size.1 - 1, frame,
+        style: &tbox.border_style, chars: tbox.border_style.chars(),
+        titles: &frame.titles.bottom, side_titles: (None, None),
+        crossings: crossing_glyphs(tbox.border_style.side(Side::Bottom).as_ref(), above, &[]),
+        left: BorderChar::BotLeft, edge: BorderChar::Bottom, right: BorderChar::BotRight
     })
 }
 
+/// Makes the row for a [Divider] in the `row`th line below the top edge, drawn like the top edge
+/// with its fitted `labels` as titles, but joined to the sides, and to the vertical lines `above`
+/// and `below` it.
+pub(super) fn make_divider(
+    buf: &mut String,
+    tbox: &TermBox,
+    (divider, labels): (&Divider, &[Title]),
+    (len, row): (usize, usize),
+    frame: &Frame,
+    (above, below): (&[Crossing], &[Crossing])
+) {
+    let border = &tbox.border_style;
+    let shape = divider.shape_in(border);
+    let (left, right) = border.junctions(&shape);
+    let chars = BorderChars { left, top: shape.chars().top, right, ..border.chars() };
+
+    make_top_or_bottom_line(buf, HorizLineArgs {
+        len, row: frame.top_rows + row, frame,
+        style: &divider.border(border), chars,
+        titles: labels, side_titles: frame.side_titles(row),
+        crossings: crossing_glyphs(Some(&shape), above, below),
+        left: BorderChar::Left, edge: BorderChar::Top, right: BorderChar::Right
+    });
+    buf.push('\n')
+}
+
+/// Returns whether the top or bottom row is drawn: hidden sides are still drawn to hold a title.
+fn draws_edge(style: &BorderStyle, titles: &[Title], side: Side) -> bool {
+    style.side(side).is_some() || titles.iter().any(|title| !title.is_empty())
+}
+
 pub(crate) const DEFAULT_DIST_FROM_CORNER: usize = 1;
 
 fn make_top_or_bottom_line(buf: &mut String, args: HorizLineArgs) {
     let style = args.style;
-    let shape = style.shape;
-    let edge_char = shape.get_char(BorderChar::Edge);
-    // String.len() is in bytes
-    let mut tmp_buf = alloc_title_buf(&args);
-    tmp_buf += shape.get_char(args.left);
+    let chars = &args.chars;
+    let (left_width, right_width) = args.frame.side_widths;
+    let edge_char = chars.get(args.edge);
+    let placed = title::layout(args.titles, args.len);
+    let crossings = &args.crossings;
+    let tmp_buf = alloc_title_buf(&args, &placed);
+    let init_cap = tmp_buf.capacity();
+    let mut painter = Painter::new(tmp_buf);
+    let push = |painter: &mut Painter, text: &str, char| match style.gradient() {
+        None => painter.push(text, style.style_of(char)),
+        // Every glyph gets its own color
+        Some(_) => for token in ansi::tokens(text) {
+            let style = style.style_at(char, (painter.col, args.row), args.frame.size);
+            painter.push(token.str(), style)
+        }
+    };
 
-    let right_char = shape.get_char(args.right);
-    if !args.title.is_empty() {
-        tmp_buf = ins_title(tmp_buf, edge_char, right_char, &args);
-    } else {
-        tmp_buf += &(edge_char.repeat(args.len - TermBox::SIDES) + right_char);
+    match &args.side_titles.0 {
+        Some(title) => painter.push_title(title, AnsiStyle::default()),
+        None => push(&mut painter, &border::pad_glyph(chars.get(args.left), left_width, true), args.left)
     }
+    // Fills the edge from column `from` up to `to`, with the glyphs where it meets any vertical lines
+    let fill = |painter: &mut Painter, from: usize, to: usize| {
+        let mut col = from;
+        for (at, glyph) in crossings.iter().filter(|&&(at, _)| (from..to).contains(&at)) {
+            let at = *at;
+            let glyph = match ansi_width(glyph) {
+                1 => glyph.to_string(),
+                _ => border::fill_glyph(edge_char, 1)
+            };
+            push(painter, &border::fill_glyph(edge_char, at - col), args.edge);
+            push(painter, &glyph, args.edge);
+            col = at + 1;
+        }
+        push(painter, &border::fill_glyph(edge_char, to - col), args.edge);
+    };
 
-    // Works in all cases except a styled right title, which would be fairly complicated
-    // for something not very worth covering for
-    // let actual = tmp_buf.len();
-    // assert!(actual == init_cap, "{actual} != {init_cap}");
-
-    if style.ansi.is_plain() {
-        buf.push_str(&tmp_buf);
-    } else {
-        buf.push_str(&style.ansi.paint(tmp_buf).to_string());
+    let mut col = 0;
+    for (start, title) in placed {
+        let (before, after) = title.decoration.ends();
+        fill(&mut painter, col, start);
+        push(&mut painter, &before, args.edge);
+        painter.push_title(&title.spaced_text(), title.style());
+        push(&mut painter, &after, args.edge);
+        col = start + title.width();
+    }
+    fill(&mut painter, col, args.len - TermBox::SIDES);
+    match &args.side_titles.1 {
+        Some(title) => painter.push_title(title, AnsiStyle::default()),
+        None => push(&mut painter, &border::pad_glyph(chars.get(args.right), right_width, false), args.right)
     }
+
+    let line = painter.finish();
+    // The estimate must cover every combination of styled titles and borders, or the buffer reallocates
+    debug_assert!(line.len() <= init_cap, "{} > {init_cap}", line.len());
+    buf.push_str(&line);
 }
 
-fn alloc_title_buf(args: &HorizLineArgs) -> String {
-    let mut cap = BorderChar::NUM_BYTES * (args.len - args.title.width());
-    cap += args.title.len_bytes();
+fn alloc_title_buf(args: &HorizLineArgs, placed: &[(usize, &Title)]) -> String {
+    let style = args.style;
+    let chars = &args.chars;
+    let (left_width, right_width) = args.frame.side_widths;
+    let titles = placed.iter().map(|&(_, title)| title);
+    // Decorations are painted as part of the edge
+    let spaced_width = |title: &Title| title.text_width() + 2 * title.spacing;
+    let edge_width = args.len - TermBox::SIDES - titles.clone().map(spaced_width).sum::<usize>();
+    // Glyphs are padded and filled with single-byte spaces, so at worst every column costs a whole glyph
+    let mut cap = chars.get(args.left).len() + left_width;
+    cap += chars.get(args.right).len() + right_width;
+    cap += args.crossings.iter().map(|(_, glyph)| glyph.len()).sum::<usize>();
+    let (left_title, right_title) = &args.side_titles;
+    cap += [left_title, right_title].into_iter().flatten().map(String::len).sum::<usize>();
+    cap += cmp::max(1, chars.get(args.edge).len()) * edge_width;
+    cap += titles.clone().map(|title| {
+        let (before, after) = title.decoration.ends();
+        title.len_bytes() + escapes_len(title.style()) + before.len() + after.len()
+    }).sum::<usize>();
+
+    // Each part of the border is painted at once, but with a gradient every glyph is painted separately
+    let parts = [args.left, args.edge, args.right].map(|char| escapes_len(style.style_of(char)));
+    cap += match style.gradient() {
+        None => parts[0] + (titles.count() + 1) * parts[1] + parts[2],
+        Some(_) => {
+            const WIDEST: Color = Color::Rgb(255, 255, 255);
+            let widest = [args.left, args.edge, args.right].into_iter()
+                .map(|char| escapes_len(AnsiStyle { foreground: Some(WIDEST), ..style.style_of(char) }))
+                .fold(0, cmp::max);
+            widest * (left_width + edge_width + right_width)
+        }
+    };
     String::with_capacity(cap)
 }
 
-fn ins_title(mut buf: String, edge_char: &str, right_char: &str, args: &HorizLineArgs) -> String {
-    let title = args.title;
-    let left_pad_len = title.left_pad_len(args.len);
+/// Returns the number of bytes the escape sequences add to text painted with the style.
+fn escapes_len(style: AnsiStyle) -> usize {
+    match style.is_plain() {
+        true => 0,
+        false => style.prefix().to_string().len() + style.suffix().to_string().len()
+    }
+}
+
+/// Builds a row of the border from pieces in different styles, painting each run of pieces that
+/// share a style at once.
+struct Painter {
+    buf: String,
+    /// The column the next piece starts at.
+    col: usize,
+    run: String,
+    style: AnsiStyle
+}
+
+impl Painter {
+    fn new(buf: String) -> Self {
+        Self { buf, col: 0, run: String::new(), style: AnsiStyle::default() }
+    }
+
+    fn push(&mut self, text: &str, style: AnsiStyle) {
+        if text.is_empty() {
+            return
+        }
 
-    buf += &edge_char.repeat(left_pad_len);
-    buf += title.text();
+        self.col += ansi_width(text);
+        if style != self.style {
+            self.flush();
+            self.style = style;
+        }
 
-    let right_pad_len = title.right_pad_len(args.len);
-    let right_pad = edge_char.repeat(right_pad_len) + right_char;
+        self.run.push_str(text)
+    }
+
+    /// Titles may contain escape sequences of their own, so they are always painted on their own,
+    /// and whatever follows them starts over in its own style.
+    fn push_title(&mut self, text: &str, style: AnsiStyle) {
+        self.flush();
+        self.style = style;
+        self.col += ansi_width(text);
+        self.run.push_str(text);
+        self.flush();
+    }
 
-    // titles may reset the style, so apply it again if we have one
-    if args.style.ansi.is_plain() {
-        buf += &right_pad;
-    } else {
-        buf += &args.style.ansi.paint(right_pad).to_string();
+    fn flush(&mut self) {
+        let run = std::mem::take(&mut self.run);
+        let painted = paint(&self.style, run);
+        self.buf.push_str(&painted);
     }
 
-    buf
+    fn finish(mut self) -> String {
+        self.flush();
+        self.buf
+    }
+}
+
+fn paint(style: &AnsiStyle, text: String) -> String {
+    match text.is_empty() || style.is_plain() {
+        true => text,
+        false => style.paint(text).to_string()
+    }
 }
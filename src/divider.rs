@@ -0,0 +1,116 @@
+use super::{AnsiStyle, BorderShape, BorderStyle, Line, Side, Title, title};
+
+/// A row across a [TermBox](super::TermBox) that splits its lines into sections, joined to the
+/// lefthand and righthand sides with the matching junction glyphs.
+///
+/// Dividers are added to a box's [lines](super::TermBox::lines) by converting them into a [Line],
+/// and always reach across the whole inside of the box, including its [padding](super::Padding).
+/// By default a divider has the same shape as the top edge of the box, or [Single](BorderShape::Single)
+/// if the top edge isn't drawn.
+///
+/// A divider may hold labels, which are [Titles](struct@Title) placed along it the same way as titles
+/// on the top and bottom edges. Labels that are too wide are handled by the box's
+/// [title_overflow](super::TermBox::title_overflow) like its titles, and are wrapped into rows just
+/// below the divider under [Wrap](super::TitleOverflow::Wrap).
+///
+/// # Examples
+///
+/// ```
+/// use term_box::*;
+///
+/// let box_ = TermBox {
+///     border_style: BorderStyle::new_double(),
+///     lines: lines![
+///         "header",
+///         Divider::new().with_shape(BorderShape::Single),
+///         "body",
+///         Divider::new().with_label(Title("end", TitlePosition::Right)),
+///         "footer"
+///     ],
+///     ..TermBox::default()
+/// };
+///
+/// let output = "
+/// ╔══════╗
+/// ║header║
+/// ╟──────╢
+/// ║body  ║
+/// ╠══end═╣
+/// ║footer║
+/// ╚══════╝
+/// ";
+///
+/// assert_eq!(box_.into_string(), output.trim());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Divider {
+    shape: Option<BorderShape>,
+    style: Option<AnsiStyle>,
+    labels: Vec<Title>
+}
+
+impl Divider {
+    /// Creates a new divider in the shape and style of the box's border, with no labels.
+    pub fn new() -> Self { Self::default() }
+
+    /// Sets the [BorderShape] of the divider and returns it.
+    pub fn with_shape(self, shape: BorderShape) -> Self {
+        Self { shape: Some(shape), ..self }
+    }
+
+    /// Sets the [AnsiStyle] of the divider, including where it joins the sides, and returns it.
+    ///
+    /// Without a style of its own, the divider is painted in the [border's style](BorderStyle::with_style),
+    /// and joins the sides in their style.
+    pub fn with_style(self, style: impl Into<AnsiStyle>) -> Self {
+        Self { style: Some(style.into()), ..self }
+    }
+
+    /// Adds a label to the divider and returns it.
+    pub fn with_label(mut self, label: Title) -> Self {
+        self.labels.push(label);
+        self
+    }
+
+    /// Returns the [BorderShape] of the divider, or [None] if it takes the shape of the box's top edge.
+    pub fn shape(&self) -> Option<BorderShape> { self.shape.clone() }
+
+    /// Returns the [AnsiStyle] of the divider, or [None] if it is painted like the border.
+    pub fn style(&self) -> Option<AnsiStyle> { self.style }
+
+    /// Returns the divider's labels.
+    pub fn labels(&self) -> &[Title] { &self.labels }
+
+    /// Returns the shape of the divider in a box with the given border.
+    pub(crate) fn shape_in(&self, border: &BorderStyle) -> BorderShape {
+        self.shape.clone().or_else(|| border.side(Side::Top)).unwrap_or_default()
+    }
+
+    /// Returns the divider's labels in a box with the given border, with their
+    /// [connectors](super::TitleDecoration::Connectors) resolved.
+    pub(crate) fn labels_in(&self, border: &BorderStyle) -> Vec<Title> {
+        let mut labels = self.labels.clone();
+        title::connect(&mut labels, Some(&self.shape_in(border)));
+        labels
+    }
+
+    /// Returns the narrowest the inside of a box with the given border can be while fitting the labels.
+    pub(crate) fn min_width(&self, border: &BorderStyle) -> usize {
+        title::min_width(&self.labels_in(border))
+    }
+
+    /// Returns the border to paint the divider with, as if it were the top edge of the box.
+    pub(crate) fn border(&self, border: &BorderStyle) -> BorderStyle {
+        let style = border.clone().with_side_style(Side::Top, self.style.unwrap_or(border.ansi_style()));
+        match self.style {
+            Some(ansi) => style.with_side_style(Side::Left, ansi).with_side_style(Side::Right, ansi),
+            None => style
+        }
+    }
+}
+
+impl From<Divider> for Line {
+    fn from(divider: Divider) -> Self {
+        Line::with_divider(divider)
+    }
+}
@@ -0,0 +1,128 @@
+use super::{Line, Side, TermBox, canvas::Canvas};
+use ansi_width::ansi_width;
+use std::io;
+
+/// [TermBoxes](TermBox) laid out in rows and columns, with the borders of neighbouring boxes
+/// merged into shared walls.
+///
+/// Every box in a column is widened to the width of the widest, and every box in a row is made
+/// as tall as the tallest by adding blank lines. Where the borders of two boxes meet, their
+/// glyphs are joined with the matching junction glyph, so mixed [BorderShapes](super::BorderShape)
+/// such as single and double lines join cleanly. Where the two borders of a shared wall differ,
+/// the box to the right or below wins, as it is drawn last. Neighbouring columns or rows only share
+/// a wall if every box on both sides of it draws the side that touches; otherwise they are placed
+/// side by side, so boxes without a side never have their contents drawn over. Rows with fewer
+/// boxes than others leave the rest of their columns empty.
+///
+/// # Examples
+///
+/// ```
+/// use term_box::*;
+///
+/// let grid = Grid::new()
+///     .with_row(vec![
+///         TermBox::default().with_lines(lines![ "cpu" ]),
+///         TermBox::default().with_lines(lines![ "memory" ])
+///     ])
+///     .with_row(vec![
+///         TermBox::default().with_lines(lines![ "disk", "io" ]),
+///         TermBox { border_style: BorderStyle::new_double(), ..TermBox::default().with_lines(lines![ "network" ]) }
+///     ]);
+///
+/// let output = "
+/// ┌────┬───────┐
+/// │cpu │memory │
+/// ├────╬═══════╣
+/// │disk║network║
+/// │io  ║       ║
+/// └────╩═══════╝
+/// ";
+///
+/// assert_eq!(grid.into_string(), output.trim());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Grid {
+    rows: Vec<Vec<TermBox>>
+}
+
+impl Grid {
+    /// Creates a new, empty grid.
+    pub fn new() -> Self { Self::default() }
+
+    /// Adds a row of boxes to the bottom of the grid and returns it.
+    pub fn with_row(mut self, boxes: Vec<TermBox>) -> Self {
+        self.rows.push(boxes);
+        self
+    }
+
+    /// Returns the grid's rows of boxes.
+    pub fn rows(&self) -> &[Vec<TermBox>] { &self.rows }
+
+    /// Prints the grid to `write` with a final newline.
+    pub fn print_to<T: io::Write>(self, write: &mut T) -> io::Result<()> {
+        writeln!(write, "{}", self.into_string())
+    }
+
+    /// Prints the grid to [stdout](io::stdout) with a final newline.
+    pub fn print(self) {
+        let _ = self.print_to(&mut io::stdout());
+    }
+
+    /// Converts the grid to a [String] for display in the terminal.
+    pub fn into_string(self) -> String {
+        let sizes: Vec<Vec<_>> = self.rows.iter()
+            .map(|row| row.iter().map(|tbox| size(&tbox.clone().into_string())).collect())
+            .collect();
+        let columns = sizes.iter().map(Vec::len).max().unwrap_or(0);
+        let widths: Vec<_> = (0..columns)
+            .map(|column| sizes.iter().filter_map(|row| row.get(column)).map(|&(width, _)| width).max().unwrap_or(0))
+            .collect();
+        let heights: Vec<_> = sizes.iter()
+            .map(|row| row.iter().map(|&(_, height)| height).max().unwrap_or(0))
+            .collect();
+        // Neighbouring boxes share the column or row where their borders meet, if both are drawn
+        let column_overlaps: Vec<_> = (1..columns)
+            .map(|column| self.rows.iter().all(|row| drawn(row.get(column - 1), Side::Right) && drawn(row.get(column), Side::Left)))
+            .collect();
+        let row_overlaps: Vec<_> = self.rows.windows(2)
+            .map(|pair| pair[0].iter().all(|tbox| drawn(Some(tbox), Side::Bottom)) && pair[1].iter().all(|tbox| drawn(Some(tbox), Side::Top)))
+            .collect();
+
+        let mut canvas = Canvas::new();
+        let mut y = 0;
+        for (row_idx, (row, sizes)) in self.rows.into_iter().zip(sizes).enumerate() {
+            let height = heights[row_idx];
+            let mut x = 0;
+            for (column, (tbox, (width, _))) in row.into_iter().zip(sizes).enumerate() {
+                canvas.draw_text(x, y, stretch(tbox, widths[column] - width, height));
+                x += widths[column] - usize::from(column_overlaps.get(column).is_some_and(|&overlap| overlap));
+            }
+            y += height.saturating_sub(usize::from(row_overlaps.get(row_idx).is_some_and(|&overlap| overlap)));
+        }
+        canvas.into_string()
+    }
+}
+
+/// Returns whether the box draws the given side. A missing box has nothing to be drawn over, so it
+/// counts as drawing every side.
+fn drawn(tbox: Option<&TermBox>, side: Side) -> bool {
+    tbox.is_none_or(|tbox| tbox.border_style.side(side).is_some())
+}
+
+/// Returns the width and height of a rendered box.
+fn size(rendered: &str) -> (usize, usize) {
+    (rendered.lines().next().map_or(0, ansi_width), rendered.lines().count())
+}
+
+/// Renders the box `extra` columns wider than it needs to be, with blank lines added to the bottom
+/// until it is `height` rows tall.
+fn stretch(mut tbox: TermBox, extra: usize, height: usize) -> String {
+    loop {
+        let rendered = tbox.clone().into_string_widened(extra);
+        let missing = height.saturating_sub(size(&rendered).1);
+        if missing == 0 {
+            return rendered
+        }
+        tbox.lines.extend(std::iter::repeat_n(Line::new(), missing));
+    }
+}
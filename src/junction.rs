@@ -0,0 +1,178 @@
+//! Lookup of box-drawing glyphs by the lines that meet in them, used wherever borders of
+//! different [BorderShapes](super::BorderShape) join.
+
+use std::array;
+
+/// The weight of a line in a box-drawing glyph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Weight {
+    Light,
+    Heavy,
+    Double
+}
+
+/// The lines leaving a glyph, in the order up, right, down, left. [None] where there is no line.
+pub(crate) type Arms = [Option<Weight>; 4];
+
+/// Returns the box-drawing glyph with exactly the given [Arms], if Unicode has one.
+pub(crate) fn glyph(arms: Arms) -> Option<&'static str> {
+    GLYPHS.iter().find(|(glyph_arms, _)| *glyph_arms == arms).map(|&(_, glyph)| glyph)
+}
+
+/// Returns the [Arms] of a box-drawing glyph, treating rounded corners like square ones.
+pub(crate) fn arms(glyph: &str) -> Option<Arms> {
+    GLYPHS.iter().chain(&ARCS).find(|&&(_, other)| other == glyph).map(|&(arms, _)| arms)
+}
+
+/// Returns the glyph where the lines of two overlapping glyphs meet, or [None] if either isn't a
+/// box-drawing glyph.
+///
+/// Where both glyphs have a line in the same direction, the line of the glyph drawn `over` the other
+/// wins. Unicode only has glyphs for some mixes of weights, so when there is no glyph for the lines
+/// as they are, the weights along each axis are made to match, then all of the lines take the
+/// weight of the glyph drawn over the other.
+pub(crate) fn merge(under: &str, over: &str) -> Option<&'static str> {
+    join(arms(under)?, arms(over)?)
+}
+
+/// Returns the glyph with the lines of both sets of [Arms], settling mismatched weights the same
+/// way as [merge].
+pub(crate) fn join(under: Arms, over: Arms) -> Option<&'static str> {
+    let merged: Arms = array::from_fn(|idx| over[idx].or(under[idx]));
+
+    let axis_weight = |axis: usize| [over, merged].iter()
+        .find_map(|arms| arms[axis].or(arms[axis + 2]));
+    let (vertical, horizontal) = (axis_weight(0), axis_weight(1));
+    let even: Arms = array::from_fn(|idx| merged[idx].and(if idx % 2 == 0 { vertical } else { horizontal }));
+    let weight = over.iter().flatten().next().copied().or(vertical).or(horizontal);
+    let uniform = merged.map(|arm| arm.and(weight));
+
+    glyph(merged).or_else(|| glyph(even)).or_else(|| glyph(uniform))
+}
+
+const N: Option<Weight> = None;
+const L: Option<Weight> = Some(Weight::Light);
+const H: Option<Weight> = Some(Weight::Heavy);
+const D: Option<Weight> = Some(Weight::Double);
+
+/// Every solid line glyph from the Box Drawing block (U+2500 to U+257F). Dashed lines, arcs and
+/// diagonals are left out.
+const GLYPHS: [(Arms, &str); 109] = [
+    ([N, L, N, L], "─"),
+    ([N, H, N, H], "━"),
+    ([L, N, L, N], "│"),
+    ([H, N, H, N], "┃"),
+    ([N, L, L, N], "┌"),
+    ([N, H, L, N], "┍"),
+    ([N, L, H, N], "┎"),
+    ([N, H, H, N], "┏"),
+    ([N, N, L, L], "┐"),
+    ([N, N, L, H], "┑"),
+    ([N, N, H, L], "┒"),
+    ([N, N, H, H], "┓"),
+    ([L, L, N, N], "└"),
+    ([L, H, N, N], "┕"),
+    ([H, L, N, N], "┖"),
+    ([H, H, N, N], "┗"),
+    ([L, N, N, L], "┘"),
+    ([L, N, N, H], "┙"),
+    ([H, N, N, L], "┚"),
+    ([H, N, N, H], "┛"),
+    ([L, L, L, N], "├"),
+    ([L, H, L, N], "┝"),
+    ([H, L, L, N], "┞"),
+    ([L, L, H, N], "┟"),
+    ([H, L, H, N], "┠"),
+    ([H, H, L, N], "┡"),
+    ([L, H, H, N], "┢"),
+    ([H, H, H, N], "┣"),
+    ([L, N, L, L], "┤"),
+    ([L, N, L, H], "┥"),
+    ([H, N, L, L], "┦"),
+    ([L, N, H, L], "┧"),
+    ([H, N, H, L], "┨"),
+    ([H, N, L, H], "┩"),
+    ([L, N, H, H], "┪"),
+    ([H, N, H, H], "┫"),
+    ([N, L, L, L], "┬"),
+    ([N, L, L, H], "┭"),
+    ([N, H, L, L], "┮"),
+    ([N, H, L, H], "┯"),
+    ([N, L, H, L], "┰"),
+    ([N, L, H, H], "┱"),
+    ([N, H, H, L], "┲"),
+    ([N, H, H, H], "┳"),
+    ([L, L, N, L], "┴"),
+    ([L, L, N, H], "┵"),
+    ([L, H, N, L], "┶"),
+    ([L, H, N, H], "┷"),
+    ([H, L, N, L], "┸"),
+    ([H, L, N, H], "┹"),
+    ([H, H, N, L], "┺"),
+    ([H, H, N, H], "┻"),
+    ([L, L, L, L], "┼"),
+    ([L, L, L, H], "┽"),
+    ([L, H, L, L], "┾"),
+    ([L, H, L, H], "┿"),
+    ([H, L, L, L], "╀"),
+    ([L, L, H, L], "╁"),
+    ([H, L, H, L], "╂"),
+    ([H, L, L, H], "╃"),
+    ([H, H, L, L], "╄"),
+    ([L, L, H, H], "╅"),
+    ([L, H, H, L], "╆"),
+    ([H, H, L, H], "╇"),
+    ([L, H, H, H], "╈"),
+    ([H, L, H, H], "╉"),
+    ([H, H, H, L], "╊"),
+    ([H, H, H, H], "╋"),
+    ([N, D, N, D], "═"),
+    ([D, N, D, N], "║"),
+    ([N, D, L, N], "╒"),
+    ([N, L, D, N], "╓"),
+    ([N, D, D, N], "╔"),
+    ([N, N, L, D], "╕"),
+    ([N, N, D, L], "╖"),
+    ([N, N, D, D], "╗"),
+    ([L, D, N, N], "╘"),
+    ([D, L, N, N], "╙"),
+    ([D, D, N, N], "╚"),
+    ([L, N, N, D], "╛"),
+    ([D, N, N, L], "╜"),
+    ([D, N, N, D], "╝"),
+    ([L, D, L, N], "╞"),
+    ([D, L, D, N], "╟"),
+    ([D, D, D, N], "╠"),
+    ([L, N, L, D], "╡"),
+    ([D, N, D, L], "╢"),
+    ([D, N, D, D], "╣"),
+    ([N, D, L, D], "╤"),
+    ([N, L, D, L], "╥"),
+    ([N, D, D, D], "╦"),
+    ([L, D, N, D], "╧"),
+    ([D, L, N, L], "╨"),
+    ([D, D, N, D], "╩"),
+    ([L, D, L, D], "╪"),
+    ([D, L, D, L], "╫"),
+    ([D, D, D, D], "╬"),
+    ([N, N, N, L], "╴"),
+    ([L, N, N, N], "╵"),
+    ([N, L, N, N], "╶"),
+    ([N, N, L, N], "╷"),
+    ([N, N, N, H], "╸"),
+    ([H, N, N, N], "╹"),
+    ([N, H, N, N], "╺"),
+    ([N, N, H, N], "╻"),
+    ([N, H, N, L], "╼"),
+    ([L, N, H, N], "╽"),
+    ([N, L, N, H], "╾"),
+    ([H, N, L, N], "╿"),
+];
+
+/// The rounded corners, which join other glyphs like the square corners do.
+const ARCS: [(Arms, &str); 4] = [
+    ([N, L, L, N], "╭"),
+    ([N, N, L, L], "╮"),
+    ([L, N, N, L], "╯"),
+    ([L, L, N, N], "╰"),
+];
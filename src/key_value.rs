@@ -0,0 +1,145 @@
+use super::{Alignment, Line, Span, TermBox, overflow};
+use std::cmp;
+
+/// `key: value` pairs laid out in two columns, which can be converted into a [TermBox].
+///
+/// Each key is followed by the separator, and the values all start in the same column, just past
+/// the widest key. Widths are measured without any ANSI escape sequences, so styled keys and values
+/// line up too. Keys are aligned within their column according to the [key_align](KeyValues::with_key_align),
+/// so right-aligned keys line up their separators as well. Values that would make a line wider than
+/// the [text_width](KeyValues::with_text_width) are wrapped, with the following lines indented to the
+/// value column.
+///
+/// # Examples
+///
+/// ```
+/// use term_box::*;
+///
+/// let info = KeyValues::new()
+///     .with_pair("name", "term-box")
+///     .with_pair(Color::Blue.paint("license"), "MIT or Apache-2.0")
+///     .with_pair("description", "Print text in pretty boxes to the terminal")
+///     .with_text_width(30);
+///
+/// let box_ = TermBox {
+///     titles: Titles::none().with_top(Title("crate", TitlePosition::Left)),
+///     padding: Padding::ONE_SPACE,
+///     ..info.into_box()
+/// };
+///
+/// let output = format!("
+/// ┌─crate──────────────────────────┐
+/// │ name:        term-box          │
+/// │ {}:     MIT or Apache-2.0 │
+/// │ description: Print text in     │
+/// │              pretty boxes to   │
+/// │              the terminal      │
+/// └────────────────────────────────┘
+/// ", Color::Blue.paint("license"));
+///
+/// assert_eq!(box_.into_string(), output.trim());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyValues {
+    pairs: Vec<(Line, Line)>,
+    separator: Line,
+    gap: usize,
+    key_align: Alignment,
+    text_width: Option<usize>
+}
+
+impl Default for KeyValues {
+    fn default() -> Self { Self::new() }
+}
+
+impl KeyValues {
+    /// The default separator after each key.
+    pub const DEFAULT_SEPARATOR: &'static str = ":";
+
+    /// Creates a new, empty list with the [default separator](KeyValues::DEFAULT_SEPARATOR) and one
+    /// space before the values.
+    pub fn new() -> Self {
+        Self {
+            pairs: Vec::new(),
+            separator: Line::from(Self::DEFAULT_SEPARATOR),
+            gap: 1,
+            key_align: Alignment::Left,
+            text_width: None
+        }
+    }
+
+    /// Adds a pair to the end of the list and returns it.
+    ///
+    /// As with [lines](super::lines), the key and value can be anything that implements [ToString],
+    /// including styled text.
+    pub fn with_pair(mut self, key: impl ToString, value: impl ToString) -> Self {
+        self.pairs.push((Line::from(key.to_string()), Line::from(value.to_string())));
+        self
+    }
+
+    /// Sets the separator displayed after each key and returns the list. Pass an empty string for
+    /// no separator.
+    pub fn with_separator(self, separator: impl ToString) -> Self {
+        Self { separator: Line::from(separator.to_string()), ..self }
+    }
+
+    /// Sets the number of spaces between the widest key's separator and the values, and returns the list.
+    pub fn with_gap(self, gap: usize) -> Self {
+        Self { gap, ..self }
+    }
+
+    /// Sets the [Alignment] of the keys within their column and returns the list.
+    pub fn with_key_align(self, key_align: Alignment) -> Self {
+        Self { key_align, ..self }
+    }
+
+    /// Sets the widest each line of text may be and returns the list. Longer values are wrapped
+    /// under the value column.
+    ///
+    /// Unlike the box's [max_width](TermBox::max_width), this doesn't count the border or
+    /// [padding](super::Padding), which are only known once the list is converted into a box.
+    pub fn with_text_width(self, text_width: usize) -> Self {
+        Self { text_width: Some(text_width), ..self }
+    }
+
+    /// Returns the pairs in the list.
+    pub fn pairs(&self) -> &[(Line, Line)] { &self.pairs }
+
+    /// Returns the separator displayed after each key.
+    pub fn separator(&self) -> &Line { &self.separator }
+
+    /// Converts the list into a [TermBox] with a line for each pair, and for each line its value is wrapped onto.
+    pub fn into_box(self) -> TermBox {
+        let key_width = self.pairs.iter().map(|(key, _)| key.width()).max().unwrap_or(0);
+        let value_col = key_width + self.separator.width() + self.gap;
+        let value_width = self.text_width.map(|max| cmp::max(1, max.saturating_sub(value_col)));
+
+        let mut lines = Vec::with_capacity(self.pairs.len());
+        for (key, value) in &self.pairs {
+            let values = match value_width {
+                Some(width) if value.width() > width => overflow::wrap(value, width),
+                _ => vec![value.clone()]
+            };
+
+            let spare = key_width - key.width();
+            let before = self.key_align.left_space(spare);
+            let mut line = Line::from(Span::plain(" ".repeat(before)));
+            key.spans().iter().chain(self.separator.spans()).for_each(|span| line.push(span.clone()));
+            line.push(Span::plain(" ".repeat(spare - before + self.gap)));
+            for (idx, value) in values.into_iter().enumerate() {
+                if idx > 0 {
+                    lines.push(line);
+                    line = Line::from(Span::plain(" ".repeat(value_col)));
+                }
+                value.spans().iter().for_each(|span| line.push(span.clone()));
+            }
+            lines.push(line);
+        }
+
+        TermBox { lines, ..TermBox::default() }
+    }
+}
+
+impl From<KeyValues> for TermBox {
+    fn from(key_values: KeyValues) -> Self { key_values.into_box() }
+}
@@ -25,7 +25,8 @@
 //!         "my",
 //!         "cool",
 //!         "box"
-//!     ]
+//!     ],
+//!     ..TermBox::default()
 //! };
 //!
 //! // Depending on terminal font, gaps between the lines in the border of the box shown in
@@ -54,8 +55,9 @@
 //!     border_style: BorderStyle::new_double().with_style(Color::Cyan),
 //!     padding: Padding::spaces(2),
 //!     titles: Titles {
-//!         top: Title("Time since unix epoch", TitlePosition::Centered),
-//!         bottom: Title::empty(),
+//!         top: vec![Title("Time since unix epoch", TitlePosition::Centered)],
+//!         bottom: vec![],
+//!         ..Titles::none()
 //!     },
 //!     lines: lines![
 //!         "",
@@ -65,7 +67,8 @@
 //!         Color::Blue.bold().paint("Irrelevant styled text to show that you can do this"),
 //!         AnsiStyle::new().italic().paint("More styled text to show another way"),
 //!         ""
-//!     ]
+//!     ],
+//!     ..TermBox::default()
 //! };
 //!
 //! time_box.print()
@@ -74,19 +77,39 @@
 #[cfg(test)]
 mod tests;
 
+mod ansi;
+mod canvas;
+mod connector;
 mod core;
+mod divider;
+mod grid;
+mod junction;
+mod key_value;
+mod overflow;
 mod padding;
+mod stack;
+mod table;
+mod terminal;
 
 pub mod border;
 pub mod line;
 pub mod title;
 
 pub use {
-    nu_ansi_term::{Color, Style as AnsiStyle},
-    border::{BorderShape, BorderStyle},
-    title::{Title, Titles, TitlePosition},
-    line::Line,
+    nu_ansi_term::{Color, Rgb, Style as AnsiStyle},
+    border::{BorderChars, BorderGradient, BorderShape, BorderStyle, Corner, GradientDirection, Side},
+    title::{Title, TitleAnchor, TitleDecoration, TitleOverflow, Titles, TitlePosition},
+    line::{Alignment, Line, Span},
+    canvas::{Canvas, Cell},
+    connector::{Connector, DrawnBox},
+    divider::Divider,
+    grid::Grid,
+    key_value::KeyValues,
+    overflow::Overflow,
     padding::Padding,
+    stack::{HStack, VerticalAlignment},
+    table::Table,
+    terminal::{terminal_width, DEFAULT_TERMINAL_WIDTH},
     core::*
 };
 
@@ -1,13 +1,16 @@
-//! [Line] type and the [lines] macro.
+//! [Line] and [Span] types, [Alignment] type and the [lines] macro.
 
-use std::cmp;
+use std::{cmp, fmt};
 use std::borrow::{Borrow, Cow};
 use ansi_width::ansi_width;
+use nu_ansi_term::{AnsiString, AnsiStrings};
+use super::{AnsiStyle, BorderShape, Divider, TermBox, ansi::{self, Token}};
 
-/// Creates a vector of [Lines](Line) for a [TermBox](super::TermBox).
+/// Creates a vector of [Lines](Line) for a [TermBox].
+///
+/// All arguments must be [Lines](Line), [Dividers](Divider), [TermBoxes](TermBox), implement [ToString] or
+/// otherwise have a `to_string` method. [Lines](Line) keep their [alignment](Line::align).
 ///
-/// All arguments must implement [ToString] or otherwise have a `to_string` method.
-/// 
 /// # Examples
 ///
 /// ```
@@ -34,21 +37,386 @@ use ansi_width::ansi_width;
 ///
 /// assert_eq!(box_.into_string(), output.trim());
 /// ```
+///
+/// Aligned lines:
+///
+/// ```
+/// use term_box::{TermBox, lines, Alignment, Line};
+///
+/// let box_ = TermBox {
+///     lines: lines![
+///         Line::from("title").aligned(Alignment::Centered),
+///         "a longer line",
+///         Line::from("right").aligned(Alignment::Right)
+///     ],
+///     ..TermBox::default()
+/// };
+///
+/// let output = "
+/// ┌─────────────┐
+/// │    title    │
+/// │a longer line│
+/// │        right│
+/// └─────────────┘
+/// ";
+///
+/// assert_eq!(box_.into_string(), output.trim());
+/// ```
 #[macro_export]
 macro_rules! lines {
-    ($($lines:expr),*) => {
-        vec![ $($lines.to_string()),* ]
-    };
+    ($($lines:expr),*) => {{
+        #[allow(unused_imports)]
+        use $crate::line::__private::{FromDisplay as _, FromLine as _};
+        vec![ $((&$crate::line::__private::LineArg($lines)).into_line()),* ]
+    }};
 }
 
 pub use lines;
 
-/// A line of text in a [TermBox](super::TermBox).
+/// Lets [lines] keep the [Alignment] of [Lines](Line) and take [Dividers](super::Divider) and
+/// [TermBoxes](super::TermBox) while converting everything else with `to_string`: method
+/// resolution finds `FromLine` on `&LineArg<Line>` before it autorefs to find `FromDisplay` on
+/// `&&LineArg<T>`.
+#[doc(hidden)]
+pub mod __private {
+    use super::{Divider, Line, TermBox};
+    use std::fmt::Display;
+
+    pub struct LineArg<T>(pub T);
+
+    pub trait FromLine {
+        fn into_line(self) -> Line;
+    }
+
+    impl FromLine for &LineArg<Line> {
+        fn into_line(self) -> Line { self.0.clone() }
+    }
+
+    impl FromLine for &LineArg<Divider> {
+        fn into_line(self) -> Line { Line::from(self.0.clone()) }
+    }
+
+    impl FromLine for &LineArg<TermBox> {
+        fn into_line(self) -> Line { Line::from(self.0.clone()) }
+    }
+
+    pub trait FromDisplay {
+        fn into_line(self) -> Line;
+    }
+
+    impl<T: Display> FromDisplay for &&LineArg<T> {
+        fn into_line(self) -> Line { Line::from(self.0.to_string()) }
+    }
+}
+
+/// The horizontal alignment of a [Line] within a [TermBox].
+///
+/// Lines without an alignment of their own use the box's [align](super::TermBox::align).
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum Alignment {
+    /// Places the line against the lefthand padding, with all spare space on the right.
+    #[default]
+    Left,
+    /// Places the line in the center of the box.
+    ///
+    /// If the spare space can't be split evenly, the extra column goes on the right.
+    Centered,
+    /// Places the line against the righthand padding, with all spare space on the left.
+    Right
+}
+
+impl Alignment {
+    /// Returns how many of the `spare` columns on a line are placed before the text.
+    pub(crate) fn left_space(self, spare: usize) -> usize {
+        match self {
+            Self::Left => 0,
+            Self::Centered => spare / 2,
+            Self::Right => spare
+        }
+    }
+}
+
+/// A run of text within a [Line] that is displayed in a single [AnsiStyle].
+///
+/// The text should not contain its own SGR (styling) escape sequences; use [Line::from] to
+/// split styled text into spans.
+///
+/// # Examples
+///
+/// ```
+/// use term_box::{AnsiStyle, Color, Span};
+///
+/// let span = Span::new("warning", Color::Yellow);
+/// assert_eq!(span.text(), "warning");
+/// assert_eq!(span.style(), Color::Yellow.normal());
+/// assert_eq!(Span::plain("ok").style(), AnsiStyle::new());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Span {
+    text: String,
+    style: AnsiStyle
+}
+
+impl Span {
+    /// Creates a new [Span] displaying the text in the given style.
+    pub fn new(text: impl ToString, style: impl Into<AnsiStyle>) -> Self {
+        Self { text: text.to_string(), style: style.into() }
+    }
+
+    /// Creates a new [Span] displaying the text without any styling.
+    pub fn plain(text: impl ToString) -> Self {
+        Self::new(text, AnsiStyle::default())
+    }
+
+    /// Returns the span's text.
+    pub fn text(&self) -> &str { &self.text }
+
+    /// Returns the span's [AnsiStyle].
+    pub fn style(&self) -> AnsiStyle { self.style }
+
+    /// Returns the number of columns the span's text takes up when displayed.
+    pub fn width(&self) -> usize { ansi_width(&self.text) }
+}
+
+/// A line of text in a [TermBox], made up of styled [Spans](Span) and
+/// an optional [Alignment].
 ///
-/// Currently, this is just an alias for a [String]. However,
-/// it may change to be a unique struct in the future. Care will be taken
-/// to not break (most) old code ([Line::new] and [Line::from] should still work).
-pub type Line = String;
+/// Lines can be created from any [str] or [String] with [Line::from]. ANSI styling in the text
+/// is split into spans, so the box knows where each style starts and ends. Other escape
+/// sequences are kept in the text of the span they appear in.
+///
+/// # Examples
+///
+/// ```
+/// use term_box::{Alignment, Color, Line, Span};
+///
+/// let line = Line::from("hello").aligned(Alignment::Right);
+/// assert_eq!(line.to_string(), "hello");
+/// assert_eq!(line.align(), Some(Alignment::Right));
+/// assert_eq!(Line::from("world").align(), None);
+///
+/// let styled = Line::from(format!("a {} line", Color::Red.paint("red")));
+/// assert_eq!(styled.spans(), [ Span::plain("a "), Span::new("red", Color::Red), Span::plain(" line") ]);
+/// assert_eq!(styled.width(), 10);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Line {
+    spans: Vec<Span>,
+    align: Option<Alignment>,
+    divider: Option<Divider>,
+    nested: Option<Box<TermBox>>,
+    /// The columns of the line's text holding vertical lines of the given shape, such as the
+    /// separators between the cells of a [Table](super::Table), which the box's edges and
+    /// [dividers](Divider) join to above and below.
+    crossings: Vec<(usize, BorderShape)>
+}
+
+impl Line {
+    /// Creates a new, empty line with no alignment of its own.
+    pub fn new() -> Self { Self::default() }
+
+    /// Sets the line's [Alignment] and returns it.
+    pub fn aligned(self, align: Alignment) -> Self {
+        Self { align: Some(align), ..self }
+    }
+
+    /// Returns the line's [Alignment], or [None] if it uses the box's alignment.
+    pub fn align(&self) -> Option<Alignment> { self.align }
+
+    /// Returns the [Divider] the line is displayed as, if it is one. See [Divider] for examples.
+    pub fn divider(&self) -> Option<&Divider> { self.divider.as_ref() }
+
+    /// Returns the [TermBox] displayed in place of the line, if there is one. See the `From<TermBox>`
+    /// implementation for examples.
+    pub fn nested(&self) -> Option<&TermBox> { self.nested.as_deref() }
+
+    /// Returns the [Spans](Span) that make up the line.
+    pub fn spans(&self) -> &[Span] { &self.spans }
+
+    /// Returns whether the line has no text and isn't displayed as a [Divider] or a nested box, so
+    /// it shows up as a blank line.
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty() && self.divider.is_none() && self.nested.is_none()
+    }
+
+    /// Returns the number of columns the line takes up when displayed, ignoring ANSI escape sequences.
+    pub fn width(&self) -> usize {
+        self.spans.iter().map(Span::width).sum()
+    }
+
+    /// Appends a [Span] to the end of the line.
+    pub fn push(&mut self, span: Span) {
+        self.push_styled(&span.text, span.style)
+    }
+
+    /// Appends text to the end of the line, splitting any ANSI styling in it into [Spans](Span).
+    ///
+    /// The appended text starts out unstyled, regardless of the style at the end of the line.
+    pub fn push_str(&mut self, text: &str) {
+        let mut style = AnsiStyle::default();
+        let mut run = String::new();
+
+        for token in ansi::tokens(text) {
+            match token {
+                Token::Escape(escape) if ansi::is_sgr(escape) => {
+                    self.push_styled(&run, style);
+                    ansi::apply_sgr(&mut style, escape);
+                    run.clear();
+                },
+                _ => run.push_str(token.str())
+            }
+        }
+
+        self.push_styled(&run, style);
+    }
+
+    pub(crate) fn with_align_of(other: &Line) -> Self {
+        Self { align: other.align, ..Self::default() }
+    }
+
+    pub(crate) fn with_divider(divider: Divider) -> Self {
+        Self { divider: Some(divider), ..Self::default() }
+    }
+
+    /// Returns the columns of the line's text that hold vertical lines, and their shapes.
+    pub(crate) fn crossings(&self) -> &[(usize, BorderShape)] { &self.crossings }
+
+    /// Marks the column of the line's text as holding a vertical line of the given shape.
+    pub(crate) fn push_crossing(&mut self, col: usize, shape: BorderShape) {
+        self.crossings.push((col, shape))
+    }
+
+    /// Appends the text in the given style, joining it to the last span if the styles match.
+    pub(crate) fn push_styled(&mut self, text: &str, style: AnsiStyle) {
+        if text.is_empty() {
+            return
+        }
+
+        match self.spans.last_mut() {
+            Some(last) if last.style == style => last.text.push_str(text),
+            _ => self.spans.push(Span { text: text.to_string(), style })
+        }
+    }
+}
+
+impl From<String> for Line {
+    fn from(text: String) -> Self {
+        Self::from(text.as_str())
+    }
+}
+
+impl From<&str> for Line {
+    fn from(text: &str) -> Self {
+        let mut line = Self::new();
+        line.push_str(text);
+        line
+    }
+}
+
+impl From<&String> for Line {
+    fn from(text: &String) -> Self {
+        Self::from(text.as_str())
+    }
+}
+
+impl From<Cow<'_, str>> for Line {
+    fn from(text: Cow<'_, str>) -> Self {
+        Self::from(text.as_ref())
+    }
+}
+
+/// Keeps the styling of the painted text as [Spans](Span).
+impl From<AnsiString<'_>> for Line {
+    fn from(text: AnsiString<'_>) -> Self {
+        Self::from(text.to_string())
+    }
+}
+
+/// Keeps the styling of the painted text as [Spans](Span).
+impl From<AnsiStrings<'_>> for Line {
+    fn from(text: AnsiStrings<'_>) -> Self {
+        Self::from(text.to_string())
+    }
+}
+
+/// Implements [From] for types that are displayed as plain text, such as numbers.
+macro_rules! from_display {
+    ($($type:ty),*) => {
+        $(impl From<$type> for Line {
+            fn from(value: $type) -> Self {
+                Self::from(value.to_string())
+            }
+        })*
+    };
+}
+
+from_display!(char, bool, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// Displays the box inside another box, in place of the line. The lines of the nested box are
+/// aligned like the line, and it is fit within the [max_width](TermBox::max_width) of the box
+/// it is displayed in, which may in turn be nested inside another.
+///
+/// # Examples
+///
+/// ```
+/// use term_box::*;
+///
+/// let inner = TermBox {
+///     border_style: BorderStyle::new_double(),
+///     lines: lines![ "a nested box inside" ],
+///     ..TermBox::default()
+/// };
+///
+/// let outer = TermBox {
+///     padding: Padding::ONE_SPACE,
+///     lines: lines![ "some text around", Line::from(inner).aligned(Alignment::Centered), "a box" ],
+///     max_width: Some(18),
+///     ..TermBox::default()
+/// };
+///
+/// let output = "
+/// ┌────────────────┐
+/// │ some text      │
+/// │ around         │
+/// │ ╔════════════╗ │
+/// │ ║a nested box║ │
+/// │ ║inside      ║ │
+/// │ ╚════════════╝ │
+/// │ a box          │
+/// └────────────────┘
+/// ";
+///
+/// assert_eq!(outer.into_string(), output.trim());
+/// ```
+impl From<TermBox> for Line {
+    fn from(tbox: TermBox) -> Self {
+        Self { nested: Some(Box::new(tbox)), ..Self::default() }
+    }
+}
+
+impl From<Span> for Line {
+    fn from(span: Span) -> Self {
+        Self::from_iter([ span ])
+    }
+}
+
+impl FromIterator<Span> for Line {
+    fn from_iter<I: IntoIterator<Item = Span>>(spans: I) -> Self {
+        let mut line = Self::new();
+        spans.into_iter().for_each(|span| line.push(span));
+        line
+    }
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let strings: Vec<_> = self.spans.iter()
+            .map(|span| span.style.paint(span.text.as_str()))
+            .collect();
+
+        write!(f, "{}", AnsiStrings(&strings))
+    }
+}
 
 #[derive(PartialEq, Eq, Debug, Clone, Default)]
 pub(crate) struct CountedString<'a> {
@@ -69,12 +437,6 @@ impl cmp::Ord for CountedString<'_> {
 }
 
 impl<'a> CountedString<'a> {
-    pub fn new(string: impl Into<Cow<'a, str>>) -> Self {
-        let str = string.into();
-        let width = ansi_width(str.borrow());
-        Self { str, width }
-    }
-
     pub fn str(&'a self) -> &'a str {
         self.str.borrow()
     }
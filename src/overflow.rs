@@ -0,0 +1,184 @@
+//! Handling for lines that are wider than a [TermBox's](super::TermBox)
+//! [max_width](super::TermBox::max_width).
+
+use super::{AnsiStyle, Line, ansi::{self, Token}};
+use ansi_width::ansi_width;
+use std::borrow::Cow;
+
+/// Describes what happens to lines that don't fit within a [TermBox's](super::TermBox)
+/// [max_width](super::TermBox::max_width).
+///
+/// # Examples
+///
+/// ```
+/// use term_box::*;
+///
+/// let box_ = TermBox {
+///     lines: lines![ "a line that is too long", "short" ],
+///     max_width: Some(12),
+///     overflow: Overflow::ELLIPSIS,
+///     ..TermBox::default()
+/// };
+///
+/// let output = "
+/// ┌──────────┐
+/// │a line th…│
+/// │short     │
+/// └──────────┘
+/// ";
+///
+/// assert_eq!(box_.into_string(), output.trim());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Overflow {
+    /// Wrap long lines at word boundaries onto as many lines as needed.
+    ///
+    /// Styles active where a line is broken carry over to the following line.
+    #[default]
+    Wrap,
+    /// Cut long lines short, ending them with the given marker.
+    ///
+    /// Escape sequences are never split, and any style still active where the line is cut
+    /// is reset before the border. The marker is displayed in the style active at the cut,
+    /// so it should be plain text.
+    Truncate(Cow<'static, str>)
+}
+
+impl Overflow {
+    /// Truncate long lines, ending them with an ellipsis (`…`).
+    pub const ELLIPSIS: Overflow = Overflow::Truncate(Cow::Borrowed("…"));
+
+    pub(crate) fn fit(&self, line: &Line, width: usize) -> Vec<Line> {
+        match self {
+            Self::Wrap => wrap(line, width),
+            Self::Truncate(marker) => vec![truncate(line, width, marker)]
+        }
+    }
+}
+
+/// A visible character or escape sequence from a [Line], along with the style of its [Span](super::Span).
+type Styled<'a> = (Token<'a>, AnsiStyle);
+
+fn styled_tokens(line: &Line) -> impl Iterator<Item = Styled<'_>> {
+    line.spans().iter().flat_map(|span| {
+        ansi::tokens(span.text()).map(move |token| (token, span.style()))
+    })
+}
+
+/// Cuts the line short so that it is at most `width` columns wide including the `marker`.
+///
+/// The marker takes the style of the text where the line is cut. If the marker itself is wider
+/// than `width`, the marker is cut instead.
+pub(crate) fn truncate(line: &Line, width: usize, marker: &str) -> Line {
+    let marker_width = ansi_width(marker);
+    if marker_width > width {
+        return truncate(&Line::from(marker), width, "")
+    }
+
+    let keep_width = width - marker_width;
+    let mut out = Line::with_align_of(line);
+    let mut out_width = 0;
+    let mut cut_style = AnsiStyle::default();
+
+    for (token, style) in styled_tokens(line) {
+        cut_style = style;
+        if out_width + token.width() > keep_width {
+            break
+        }
+
+        out.push_styled(token.str(), style);
+        out_width += token.width();
+    }
+
+    out.push_styled(marker, cut_style);
+    out
+}
+
+/// Wraps the line at word boundaries so that no returned line is wider than `width`,
+/// unless a single character is wider than `width` by itself.
+///
+/// Words wider than `width` are split between characters. Each [Span](super::Span) keeps its
+/// style across breaks, and every returned line has the alignment of the original. Spaces at the
+/// start of the line are dropped if the first word doesn't fit after them.
+pub(crate) fn wrap(line: &Line, width: usize) -> Vec<Line> {
+    let mut wrapper = Wrapper::new(line, width);
+    let mut word = Vec::new();
+
+    for styled in styled_tokens(line) {
+        match styled {
+            (Token::Char(' ', _), _) => {
+                wrapper.push_word(&word);
+                wrapper.pending_spaces.push(styled);
+                word.clear();
+            },
+            _ => word.push(styled)
+        }
+    }
+    wrapper.push_word(&word);
+
+    wrapper.finish()
+}
+
+struct Wrapper<'a> {
+    width: usize,
+    lines: Vec<Line>,
+    cur: Line,
+    cur_width: usize,
+    pending_spaces: Vec<Styled<'a>>
+}
+
+impl<'a> Wrapper<'a> {
+    fn new(line: &Line, width: usize) -> Self {
+        Self {
+            width,
+            lines: Vec::new(),
+            cur: Line::with_align_of(line),
+            cur_width: 0,
+            pending_spaces: Vec::new()
+        }
+    }
+
+    fn push_word(&mut self, word: &[Styled<'a>]) {
+        let width: usize = word.iter().map(|(token, _)| token.width()).sum();
+
+        // Escape-only words don't take up space, so they never cause a break
+        if width == 0 {
+            return word.iter().for_each(|&styled| self.push_token(styled))
+        }
+
+        let spaces_width = self.pending_spaces.len();
+        if self.cur_width > 0 && self.cur_width + spaces_width + width > self.width {
+            self.break_line();
+        }
+
+        // Spaces at the start of the line are kept as an indent only if the first word still fits
+        let spaces = std::mem::take(&mut self.pending_spaces);
+        if self.cur_width > 0 || (self.lines.is_empty() && spaces_width + width <= self.width) {
+            spaces.into_iter().for_each(|styled| self.push_token(styled));
+        }
+
+        for &styled in word {
+            if self.cur_width > 0 && self.cur_width + styled.0.width() > self.width {
+                self.break_line();
+            }
+            self.push_token(styled);
+        }
+    }
+
+    fn push_token(&mut self, (token, style): Styled<'a>) {
+        self.cur.push_styled(token.str(), style);
+        self.cur_width += token.width();
+    }
+
+    fn break_line(&mut self) {
+        let next = Line::with_align_of(&self.cur);
+        self.lines.push(std::mem::replace(&mut self.cur, next));
+        self.cur_width = 0;
+        self.pending_spaces.clear();
+    }
+
+    fn finish(mut self) -> Vec<Line> {
+        self.lines.push(self.cur);
+        self.lines
+    }
+}
@@ -5,17 +5,47 @@ use super::CountedString;
 /// Represents the padding between the edge of the [TermBox] and the text
 /// it contains.
 ///
-/// Padding appears between the horizontal edges of a [TermBox] and
-/// the lines of text within. To vertically pad a [TermBox], add blank lines
-/// to the start and end of the [lines](TermBox::lines) vector.
+/// Each side of the box is padded separately. Left and right padding appears between the
+/// vertical edges of a [TermBox] and each line of text within. Top and bottom padding adds
+/// rows of spaces between the horizontal edges and the text. These rows are not part of the
+/// box's [lines](TermBox::lines).
 ///
 /// By default, boxes have no padding ([Padding::none]).
-#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+///
+/// # Examples
+///
+/// ```
+/// use term_box::{lines, Padding, TermBox};
+///
+/// let box_ = TermBox {
+///     padding: Padding::spaces(2).with_vertical(1),
+///     lines: lines![ "roomy" ],
+///     ..TermBox::default()
+/// };
+///
+/// let output = "
+/// ┌─────────┐
+/// │         │
+/// │  roomy  │
+/// │         │
+/// └─────────┘
+/// ";
+///
+/// assert_eq!(box_.lines.len(), 1);
+/// assert_eq!(box_.into_string(), output.trim());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Padding {
     /// The [char] used to provide the padding (usually spaces or tabs).
     chr: char,
-    /// The number of the [chr](Padding::chr) that should be used for the padding.
-    count: usize
+    /// The number of blank rows above the text.
+    top: usize,
+    /// The number of the [chr](Padding::chr) to the right of the text.
+    right: usize,
+    /// The number of blank rows below the text.
+    bottom: usize,
+    /// The number of the [chr](Padding::chr) to the left of the text.
+    left: usize
 }
 
 impl Padding {
@@ -31,9 +61,10 @@ impl Padding {
     /// assert_eq!(Padding::default(), Padding::none());
     /// assert_eq!("", Padding::none().into_string());
     /// ```
-    pub const fn none() -> Self { Self::new('\0', 0) }
+    pub const fn none() -> Self { Self::spaces(0) }
 
-    /// Creates a new [Padding] that pads with the given character and number of spaces.
+    /// Creates a new [Padding] that pads the left and right of the text with the given character
+    /// and number of spaces.
     ///
     /// If the passed [char] is a tab character, it will be replaced with 8 spaces to
     /// prevent misaligned edges. Other whitespace characters are not accounted for
@@ -58,11 +89,11 @@ impl Padding {
     pub const fn new(chr: char, count: usize) -> Self {
         match chr {
             '\t' => Self::spaces(count * 8),
-            _    => Self { chr, count }
+            _    => Self { chr, top: 0, right: count, bottom: 0, left: count }
         }
     }
 
-    /// Creates a new [Padding] that pads with the given number of spaces.
+    /// Creates a new [Padding] that pads the left and right of the text with the given number of spaces.
     ///
     /// # Examples
     ///
@@ -74,22 +105,85 @@ impl Padding {
     /// assert_eq!(" ", padding.into_string());
     /// ```
     pub const fn spaces(count: usize) -> Self {
-        Self { chr: ' ', count }
+        Self::sides(0, count, 0, count)
+    }
+
+    /// Creates a new [Padding] that pads each side of the text with spaces, in the same
+    /// order as CSS: `top`, `right`, `bottom`, `left`.
+    ///
+    /// `top` and `bottom` are numbers of blank rows; `right` and `left` are numbers of spaces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use term_box::Padding;
+    ///
+    /// let padding = Padding::sides(1, 2, 3, 4);
+    /// assert_eq!((padding.top(), padding.right(), padding.bottom(), padding.left()), (1, 2, 3, 4));
+    /// ```
+    pub const fn sides(top: usize, right: usize, bottom: usize, left: usize) -> Self {
+        Self { chr: ' ', top, right, bottom, left }
+    }
+
+    /// Sets the number of blank rows above the text and returns the padding.
+    pub const fn with_top(self, top: usize) -> Self {
+        Self { top, ..self }
+    }
+
+    /// Sets the number of [chrs](Padding::chr) to the right of the text and returns the padding.
+    pub const fn with_right(self, right: usize) -> Self {
+        Self { right, ..self }
+    }
+
+    /// Sets the number of blank rows below the text and returns the padding.
+    pub const fn with_bottom(self, bottom: usize) -> Self {
+        Self { bottom, ..self }
+    }
+
+    /// Sets the number of [chrs](Padding::chr) to the left of the text and returns the padding.
+    pub const fn with_left(self, left: usize) -> Self {
+        Self { left, ..self }
     }
 
-    /// Gets the length of the padding in bytes once converted into a string.
+    /// Sets the number of blank rows both above and below the text and returns the padding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use term_box::Padding;
+    ///
+    /// assert_eq!(Padding::ONE_SPACE.with_vertical(2), Padding::sides(2, 1, 2, 1));
+    /// ```
+    pub const fn with_vertical(self, count: usize) -> Self {
+        Self { top: count, bottom: count, ..self }
+    }
+
+    /// Gets the length of the left padding in bytes once converted into a string.
     pub const fn len_utf8(self) -> usize {
-        self.chr.len_utf8() * self.count
+        self.chr.len_utf8() * self.left
     }
 
     /// Returns the [char] used for padding.
     pub const fn chr(self) -> char { self.chr }
 
     /// Returns the number of times the [chr](Padding::chr) will be
-    /// repeated in padding.
-    pub const fn count(self) -> usize { self.count }
+    /// repeated in the left padding.
+    #[deprecated(note = "the left and right padding may differ; use `Padding::left` or `Padding::right`")]
+    pub const fn count(self) -> usize { self.left }
+
+    /// Returns the number of blank rows above the text.
+    pub const fn top(self) -> usize { self.top }
 
-    /// Converts the padding into a string and returns it.
+    /// Returns the number of times the [chr](Padding::chr) will be repeated to the right of the text.
+    pub const fn right(self) -> usize { self.right }
+
+    /// Returns the number of blank rows below the text.
+    pub const fn bottom(self) -> usize { self.bottom }
+
+    /// Returns the number of times the [chr](Padding::chr) will be repeated to the left of the text.
+    pub const fn left(self) -> usize { self.left }
+
+    /// Converts the left padding into a string and returns it.
     ///
     /// # Examples
     ///
@@ -100,13 +194,29 @@ impl Padding {
     /// assert_eq!("aaa", padding.into_string());
     /// ```
     pub fn into_string(self) -> String {
-        String::from(self.chr).repeat(self.count)
+        self.repeat(self.left)
     }
 
-    pub(super) fn into_counted_string(self) -> CountedString<'static> {
-        match self.count {
+    pub(super) const fn horizontal(self) -> usize {
+        self.left + self.right
+    }
+
+    fn repeat(self, count: usize) -> String {
+        String::from(self.chr).repeat(count)
+    }
+
+    pub(super) fn into_counted_strings(self) -> (CountedString<'static>, CountedString<'static>) {
+        (self.counted(self.left), self.counted(self.right))
+    }
+
+    fn counted(self, count: usize) -> CountedString<'static> {
+        match count {
             0 => CountedString::EMPTY,
-            n => CountedString::counted(self.into_string(), n)
+            n => CountedString::counted(self.repeat(n), n)
         }
     }
 }
+
+impl Default for Padding {
+    fn default() -> Self { Self::none() }
+}
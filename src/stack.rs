@@ -0,0 +1,144 @@
+use super::TermBox;
+use ansi_width::ansi_width;
+use std::io;
+
+/// The vertical alignment of the boxes in an [HStack] that are shorter than the tallest.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum VerticalAlignment {
+    /// Places the box against the top of the stack, with all spare rows below it.
+    #[default]
+    Top,
+    /// Places the box in the middle of the stack.
+    ///
+    /// If the spare rows can't be split evenly, the extra row goes below the box.
+    Middle,
+    /// Places the box against the bottom of the stack, with all spare rows above it.
+    Bottom
+}
+
+impl VerticalAlignment {
+    /// Returns how many of the `spare` rows beside a box are placed above it.
+    pub(crate) fn top_space(self, spare: usize) -> usize {
+        match self {
+            Self::Top => 0,
+            Self::Middle => spare / 2,
+            Self::Bottom => spare
+        }
+    }
+}
+
+/// [TermBoxes](TermBox) displayed side by side, separated by a gap.
+///
+/// Boxes shorter than the tallest are filled out with blank rows according to the stack's
+/// [VerticalAlignment], so every line of the output is the same width. Widths are measured
+/// without any ANSI escape sequences, so styled boxes line up too.
+///
+/// # Examples
+///
+/// ```
+/// use term_box::*;
+///
+/// let stack = HStack::new()
+///     .with_box(TermBox::default().with_lines(lines![ "db" ]))
+///     .with_box(TermBox::default().with_lines(lines![ "web", Color::Green.paint("up"), "ok" ]))
+///     .with_align(VerticalAlignment::Middle);
+///
+/// let output = format!("
+///      ┌───┐
+/// ┌──┐ │web│
+/// │db│ │{} │
+/// └──┘ │ok │
+///      └───┘
+/// ", Color::Green.paint("up"));
+///
+/// assert_eq!(stack.into_string(), output.trim_matches('\n'));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HStack {
+    boxes: Vec<TermBox>,
+    gap: usize,
+    align: VerticalAlignment
+}
+
+impl Default for HStack {
+    fn default() -> Self { Self::new() }
+}
+
+impl HStack {
+    /// The default number of spaces between boxes.
+    pub const DEFAULT_GAP: usize = 1;
+
+    /// Creates a new, empty stack with the [default gap](HStack::DEFAULT_GAP) between boxes.
+    pub fn new() -> Self {
+        Self { boxes: Vec::new(), gap: Self::DEFAULT_GAP, align: VerticalAlignment::default() }
+    }
+
+    /// Adds a box to the right of the stack and returns it.
+    ///
+    /// Anything that converts into a [TermBox], such as a [Table](super::Table), can be added.
+    pub fn with_box(mut self, tbox: impl Into<TermBox>) -> Self {
+        self.boxes.push(tbox.into());
+        self
+    }
+
+    /// Sets the number of spaces between boxes and returns the stack.
+    pub fn with_gap(self, gap: usize) -> Self {
+        Self { gap, ..self }
+    }
+
+    /// Sets the [VerticalAlignment] of the boxes and returns the stack.
+    pub fn with_align(self, align: VerticalAlignment) -> Self {
+        Self { align, ..self }
+    }
+
+    /// Returns the boxes in the stack, from left to right.
+    pub fn boxes(&self) -> &[TermBox] { &self.boxes }
+
+    /// Returns the number of spaces between boxes.
+    pub fn gap(&self) -> usize { self.gap }
+
+    /// Returns the [VerticalAlignment] of the boxes.
+    pub fn align(&self) -> VerticalAlignment { self.align }
+
+    /// Prints the stack to `write` with a final newline.
+    pub fn print_to<T: io::Write>(self, write: &mut T) -> io::Result<()> {
+        writeln!(write, "{}", self.into_string())
+    }
+
+    /// Prints the stack to [stdout](io::stdout) with a final newline.
+    pub fn print(self) {
+        let _ = self.print_to(&mut io::stdout());
+    }
+
+    /// Converts the stack to a [String] for display in the terminal.
+    pub fn into_string(self) -> String {
+        let rendered: Vec<String> = self.boxes.into_iter().map(TermBox::into_string).collect();
+        let columns: Vec<(Vec<&str>, usize)> = rendered.iter()
+            .map(|tbox| {
+                let lines: Vec<_> = tbox.lines().collect();
+                let width = lines.first().map_or(0, |line| ansi_width(line));
+                (lines, width)
+            })
+            .collect();
+        let height = columns.iter().map(|(lines, _)| lines.len()).max().unwrap_or(0);
+        let gap = " ".repeat(self.gap);
+
+        let mut buf = String::new();
+        for row in 0..height {
+            if row > 0 {
+                buf.push('\n');
+            }
+            for (idx, (lines, width)) in columns.iter().enumerate() {
+                if idx > 0 {
+                    buf.push_str(&gap);
+                }
+                let top = self.align.top_space(height - lines.len());
+                match row.checked_sub(top).and_then(|row| lines.get(row)) {
+                    Some(line) => buf.push_str(line),
+                    None => buf.push_str(&" ".repeat(*width))
+                }
+            }
+        }
+        buf
+    }
+}
@@ -0,0 +1,216 @@
+use super::{Alignment, BorderShape, BorderStyle, Divider, Line, Side, Span, TermBox};
+use ansi_width::ansi_width;
+
+/// Rows of cells laid out in columns, which can be converted into a [TermBox].
+///
+/// Each column is as wide as its widest cell, measured without any ANSI escape sequences, and the
+/// columns are separated by vertical lines that join the edges of the box, and the [Divider] below
+/// the optional header row, with the matching junction glyphs. Rows with fewer cells than others are
+/// filled out with empty cells.
+///
+/// Cells are aligned within their column according to the column's [Alignment], unless the cell's
+/// [Line] has an [alignment](Line::align) of its own. Rows are never wrapped or truncated to fit the
+/// box's [max_width](TermBox::max_width), as that would break up the lines between columns.
+///
+/// # Examples
+///
+/// ```
+/// use term_box::*;
+///
+/// let table = Table::new()
+///     .with_header(lines![ "crate", "version" ])
+///     .with_row(lines![ "term-box", "0.1.0" ])
+///     .with_row(lines![ "ansi-width", Color::Green.paint("0.1.0") ])
+///     .with_align(1, Alignment::Right);
+///
+/// let output = format!("
+/// ┌────────────┬─────────┐
+/// │ crate      │ version │
+/// ├────────────┼─────────┤
+/// │ term-box   │   0.1.0 │
+/// │ ansi-width │   {} │
+/// └────────────┴─────────┘
+/// ", Color::Green.paint("0.1.0"));
+///
+/// assert_eq!(table.into_box().into_string(), output.trim());
+/// ```
+///
+/// The box can be given titles, padding and so on like any other:
+///
+/// ```
+/// use term_box::*;
+///
+/// let table = Table::new()
+///     .with_border_style(BorderStyle::new_double())
+///     .with_separator(BorderShape::Single)
+///     .with_row(lines![ "a", "b" ])
+///     .with_row(lines![ "c" ]);
+///
+/// let box_ = TermBox {
+///     titles: Titles::none().with_top(Title("ab", TitlePosition::Left)),
+///     ..table.into_box()
+/// };
+///
+/// let output = "
+/// ╔═ab╤═══╗
+/// ║ a │ b ║
+/// ║ c │   ║
+/// ╚═══╧═══╝
+/// ";
+///
+/// assert_eq!(box_.into_string(), output.trim());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    border_style: BorderStyle,
+    header: Option<Vec<Line>>,
+    header_divider: Divider,
+    rows: Vec<Vec<Line>>,
+    aligns: Vec<Alignment>,
+    separator: Option<BorderShape>,
+    cell_padding: usize
+}
+
+impl Default for Table {
+    fn default() -> Self { Self::new() }
+}
+
+impl Table {
+    /// The default number of spaces on either side of each cell.
+    pub const DEFAULT_CELL_PADDING: usize = 1;
+
+    /// Creates a new, empty table with the [default](BorderStyle::default) border.
+    pub fn new() -> Self {
+        Self {
+            border_style: BorderStyle::default(),
+            header: None,
+            header_divider: Divider::new(),
+            rows: Vec::new(),
+            aligns: Vec::new(),
+            separator: None,
+            cell_padding: Self::DEFAULT_CELL_PADDING
+        }
+    }
+
+    /// Sets the [BorderStyle] of the box and returns the table.
+    pub fn with_border_style(self, border_style: BorderStyle) -> Self {
+        Self { border_style, ..self }
+    }
+
+    /// Sets the header row, which is separated from the other rows by the [header divider](Table::with_header_divider),
+    /// and returns the table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the cells is a [Divider] or a nested [TermBox].
+    pub fn with_header(self, cells: Vec<Line>) -> Self {
+        check_cells(&cells);
+        Self { header: Some(cells), ..self }
+    }
+
+    /// Sets the [Divider] below the header row and returns the table. By default, it has the shape
+    /// of the box's top edge.
+    pub fn with_header_divider(self, header_divider: Divider) -> Self {
+        Self { header_divider, ..self }
+    }
+
+    /// Adds a row of cells to the table and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the cells is a [Divider] or a nested [TermBox].
+    pub fn with_row(mut self, cells: Vec<Line>) -> Self {
+        check_cells(&cells);
+        self.rows.push(cells);
+        self
+    }
+
+    /// Sets the [Alignment] of the cells in the given column, counting from 0, and returns the table.
+    /// Columns are aligned [left](Alignment::Left) by default.
+    pub fn with_align(mut self, column: usize, align: Alignment) -> Self {
+        if self.aligns.len() <= column {
+            self.aligns.resize(column + 1, Alignment::default());
+        }
+        self.aligns[column] = align;
+        self
+    }
+
+    /// Sets the [BorderShape] of the lines between columns and returns the table. By default, they
+    /// have the shape of the lefthand side of the box, or [Single](BorderShape::Single) if it isn't drawn.
+    pub fn with_separator(self, separator: BorderShape) -> Self {
+        Self { separator: Some(separator), ..self }
+    }
+
+    /// Sets the number of spaces on either side of each cell and returns the table.
+    pub fn with_cell_padding(self, cell_padding: usize) -> Self {
+        Self { cell_padding, ..self }
+    }
+
+    /// Returns the header row, if the table has one.
+    pub fn header(&self) -> Option<&[Line]> { self.header.as_deref() }
+
+    /// Returns the table's rows, not including the header.
+    pub fn rows(&self) -> &[Vec<Line>] { &self.rows }
+
+    /// Returns the [Alignment] of the cells in the given column.
+    pub fn align(&self, column: usize) -> Alignment {
+        self.aligns.get(column).copied().unwrap_or_default()
+    }
+
+    /// Converts the table into a [TermBox] with a line for each row.
+    pub fn into_box(self) -> TermBox {
+        let all_rows = || self.header.iter().chain(self.rows.iter());
+        let columns = all_rows().map(Vec::len).max().unwrap_or(0);
+        let widths: Vec<_> = (0..columns)
+            .map(|column| all_rows().filter_map(|row| row.get(column)).map(Line::width).max().unwrap_or(0))
+            .collect();
+
+        let mut lines = Vec::with_capacity(self.rows.len() + 2);
+        if let Some(header) = &self.header {
+            lines.push(self.make_row(header, &widths));
+            lines.push(Line::from(self.header_divider.clone()));
+        }
+        lines.extend(self.rows.iter().map(|row| self.make_row(row, &widths)));
+
+        TermBox { border_style: self.border_style, lines, ..TermBox::default() }
+    }
+
+    /// Lays out a row of cells in columns `widths` wide, separated by vertical lines.
+    fn make_row(&self, cells: &[Line], widths: &[usize]) -> Line {
+        let separator = self.separator.clone().or_else(|| self.border_style.side(Side::Left)).unwrap_or_default();
+        let glyph = separator.chars().left;
+        let empty = Line::new();
+        let spaces = |count| Span::plain(" ".repeat(count));
+
+        let mut line = Line::new();
+        let mut col = 0;
+        for (column, &width) in widths.iter().enumerate() {
+            if column > 0 {
+                line.push_crossing(col, separator.clone());
+                line.push(Span::new(&glyph, self.border_style.ansi_style()));
+                col += ansi_width(&glyph);
+            }
+
+            let cell = cells.get(column).unwrap_or(&empty);
+            let spare = width - cell.width();
+            let before = cell.align().unwrap_or(self.align(column)).left_space(spare);
+            line.push(spaces(self.cell_padding + before));
+            cell.spans().iter().for_each(|span| line.push(span.clone()));
+            line.push(spaces(spare - before + self.cell_padding));
+            col += width + 2 * self.cell_padding;
+        }
+        line
+    }
+}
+
+/// Checks that every cell is text, as dividers and boxes can't be laid out on a single row.
+fn check_cells(cells: &[Line]) {
+    assert!(
+        cells.iter().all(|cell| cell.divider().is_none() && cell.nested().is_none()),
+        "table cells must be text, not dividers or boxes"
+    );
+}
+
+impl From<Table> for TermBox {
+    fn from(table: Table) -> Self { table.into_box() }
+}
@@ -0,0 +1,38 @@
+//! Detection of the width of the terminal that boxes are printed to.
+
+use std::{env, io};
+
+/// The width in columns assumed by [terminal_width] when it cannot be detected.
+pub const DEFAULT_TERMINAL_WIDTH: usize = 80;
+
+/// Returns the width of the current terminal in columns.
+///
+/// The width is detected from the terminal attached to [stdout](io::stdout), then
+/// [stderr](io::stderr). If neither is a terminal, the `COLUMNS` environment variable is used,
+/// and if that is not set to a positive number, [DEFAULT_TERMINAL_WIDTH] is returned.
+///
+/// # Examples
+///
+/// ```
+/// use term_box::terminal_width;
+///
+/// assert!(terminal_width() > 0);
+/// ```
+pub fn terminal_width() -> usize {
+    tty_width()
+        .or_else(env_width)
+        .unwrap_or(DEFAULT_TERMINAL_WIDTH)
+}
+
+fn tty_width() -> Option<usize> {
+    terminal_size::terminal_size_of(io::stdout())
+        .or_else(|| terminal_size::terminal_size_of(io::stderr()))
+        .map(|(terminal_size::Width(width), _)| width as usize)
+        .filter(|&width| width > 0)
+}
+
+fn env_width() -> Option<usize> {
+    env::var("COLUMNS").ok()
+        .and_then(|columns| columns.trim().parse().ok())
+        .filter(|&width| width > 0)
+}
@@ -14,6 +14,131 @@ fn empty() {
     assert_eq!(box_line, "┌─┐\n│ │\n└─┘", "single w/ empty line");
 }
 
+#[test]
+fn shapes() {
+    let shape_box = |shape: BorderShape| TermBox {
+        border_style: shape.into(),
+        titles: Titles { top: vec![Title("t", TitlePosition::Left)], bottom: vec![], ..Titles::none() },
+        lines: lines![ "box" ],
+        ..TermBox::default()
+    }.into_string();
+
+    assert_eq!(shape_box(BorderShape::Rounded), "╭─t─╮\n│box│\n╰───╯", "rounded");
+    assert_eq!(shape_box(BorderShape::Heavy), "┏━t━┓\n┃box┃\n┗━━━┛", "heavy");
+    assert_eq!(shape_box(BorderShape::Dashed), "┌╌t╌┐\n╎box╎\n└╌╌╌┘", "dashed");
+    assert_eq!(shape_box(BorderShape::Dotted), "┌┈t┈┐\n┊box┊\n└┈┈┈┘", "dotted");
+    assert_eq!(shape_box(BorderShape::Ascii), "+-t-+\n|box|\n+---+", "ascii");
+    assert_eq!(shape_box(BorderShape::Hidden), "  t  \n box \n     ", "hidden");
+}
+
+#[test]
+fn custom_shapes() {
+    let custom_box = |chars: BorderChars, title: &str| TermBox {
+        border_style: BorderStyle::from(BorderShape::Custom(chars)).with_style(Color::Blue),
+        padding: Padding::ONE_SPACE,
+        titles: Titles { top: vec![Title(title, TitlePosition::Centered)], bottom: vec![], ..Titles::none() },
+        lines: lines![ "custom", "glyphs" ],
+        ..TermBox::default()
+    }.into_string();
+
+    let wide = custom_box(BorderChars::uniform("＃"), "odd");
+    let mixed = custom_box(BorderChars {
+        top_left: "<=".into(), top: "=".into(), top_right: "=>".into(),
+        left: "|".into(), right: "|".into(),
+        bottom_left: "<-".into(), bottom: "--".into(), bottom_right: "->".into()
+    }, "title");
+
+    assert_okay!(lines_same_len(&wide), "wide");
+    assert_okay!(lines_same_len(&mixed), "mixed");
+    assert_matches_template!(format!("{wide}\n{mixed}"), "custom-shapes");
+}
+
+#[test]
+fn sides() {
+    let sides_box = |style: BorderStyle, top: &str| TermBox {
+        border_style: style,
+        titles: Titles { top: vec![Title(top, TitlePosition::Left)], bottom: vec![], ..Titles::none() },
+        lines: lines![ "box" ],
+        ..TermBox::default()
+    }.into_string();
+
+    let mixed = BorderStyle::new_double()
+        .with_side(Side::Left, BorderShape::Single)
+        .with_side(Side::Bottom, BorderShape::Heavy);
+    let open = BorderStyle::new_single().with_side(Side::Right, None);
+    let bare = BorderStyle::new_single().with_side(Side::Top, None).with_side(Side::Bottom, None);
+    let ascii_top = BorderStyle::new_single().with_side(Side::Top, BorderShape::Ascii);
+
+    assert_eq!(sides_box(mixed, ""), "╒═══╗\n│box║\n┕━━━┛", "mixed");
+    assert_eq!(sides_box(open, "t"), "┌─t─\n│box\n└───", "open");
+    assert_eq!(sides_box(bare.clone(), ""), "│box│", "bare");
+    assert_eq!(sides_box(bare, "t"), "  t  \n│box│", "bare w/ title");
+    assert_eq!(sides_box(ascii_top, ""), "+---+\n│box│\n└───┘", "ascii top");
+}
+
+#[test]
+fn styled_parts() {
+    let box_ = TermBox {
+        border_style: BorderStyle::new_single()
+            .with_style(Color::DarkGray)
+            .with_side_style(Side::Top, Color::LightGreen.bold())
+            .with_side_style(Side::Right, Color::Blue)
+            .with_corner_style(Corner::TopLeft, Color::Green)
+            .with_corner_style(Corner::BottomRight, AnsiStyle::new()),
+        padding: Padding::ONE_SPACE.with_vertical(1),
+        titles: Titles {
+            top: vec![Title(Color::White.paint("status"), TitlePosition::Centered)],
+            bottom: vec![Title("dim", TitlePosition::Right)],
+            ..Titles::none()
+        },
+        lines: lines![ "all systems", "operational" ],
+        ..TermBox::default()
+    }.into_string();
+
+    assert_okay!(lines_same_len(&box_));
+    assert_matches_template!(box_, "styled-parts");
+}
+
+#[test]
+fn gradients() {
+    const COLORS: &[Rgb] = &[ Rgb::new(255, 0, 0), Rgb::new(0, 255, 0), Rgb::new(0, 0, 255) ];
+    let gradient_box = |direction: GradientDirection| TermBox {
+        border_style: BorderStyle::new_single()
+            .with_style(*BOLD)
+            .with_gradient(BorderGradient::new(COLORS, direction)),
+        titles: Titles { top: vec![Title(Color::Yellow.paint("title"), TitlePosition::Centered)], bottom: vec![], ..Titles::none() },
+        lines: lines![ "a gradient", "border" ],
+        ..TermBox::default()
+    }.into_string();
+
+    let around = gradient_box(GradientDirection::Around);
+    let vertical = gradient_box(GradientDirection::Vertical);
+    let horizontal = gradient_box(GradientDirection::Horizontal);
+
+    assert!(around.starts_with("\x1b[1;38;2;255;0;0m┌"), "around starts with the first color");
+    assert!(vertical.lines().last().unwrap().starts_with("\x1b[1;38;2;0;0;255m└"), "vertical ends with the last color");
+    assert_okay!(lines_same_len(&around), "around");
+    assert_okay!(lines_same_len(&vertical), "vertical");
+    assert_okay!(lines_same_len(&horizontal), "horizontal");
+    assert_matches_template!(format!("{around}\n{vertical}\n{horizontal}"), "gradients");
+}
+
+#[test]
+fn gradient_single_color() {
+    let gradient_box = |direction: GradientDirection| TermBox {
+        border_style: BorderStyle::new_single().with_gradient(BorderGradient::new(vec![ Rgb::new(1, 2, 3) ], direction)),
+        lines: lines![ "one color" ],
+        ..TermBox::default()
+    }.into_string();
+
+    for direction in [GradientDirection::Around, GradientDirection::Vertical, GradientDirection::Horizontal] {
+        let box_ = gradient_box(direction);
+        let colored = box_.matches("\x1b[38;2;").count();
+        assert!(colored > 0, "{direction:?} is colored");
+        assert_eq!(box_.matches("\x1b[38;2;1;2;3m").count(), colored, "{direction:?} only uses the one color");
+    }
+}
+
 #[test]
 fn empty_styled() {
     let box_ = TermBox {
@@ -115,13 +240,55 @@ fn padded_with_ansi_text() {
             "cool",
             AnsiStrings(&[ Color::Red.paint("pa"), Color::Default.paint("dd"), Color::Purple.paint("ed") ]),
             Color::Blue.paint("text")
-        ]
+        ],
+        ..TermBox::default()
     }.into_string();
 
     assert_okay!(lines_same_len(&box_));
     assert_matches_template!(box_, "padded-with-ansi-text")
 }
 
+#[test]
+fn padded_sides() {
+    let tbox = TermBox {
+        padding: Padding::sides(1, 3, 2, 1),
+        lines: lines![
+            "four",
+            "sides"
+        ],
+        ..TermBox::default()
+    };
+    let lines = tbox.lines.clone();
+    let box_ = tbox.into_string();
+
+    assert_okay!(lines_same_len(&box_));
+    assert_eq!(lines.len(), 2);
+    assert_eq!(box_, "\
+┌─────────┐
+│         │
+│ four    │
+│ sides   │
+│         │
+│         │
+└─────────┘");
+}
+
+#[test]
+fn padded_vertical_styled() {
+    let box_ = TermBox {
+        border_style: BorderStyle::new_double().with_style(Color::Yellow),
+        padding: Padding::new('·', 2).with_vertical(1),
+        lines: lines![
+            Color::Red.paint("dotted"),
+            "sides"
+        ],
+        ..TermBox::default()
+    }.into_string();
+
+    assert_okay!(lines_same_len(&box_));
+    assert_matches_template!(box_, "padded-vertical-styled");
+}
+
 #[test]
 fn fat_box() {
     let box_ = TermBox {
@@ -143,7 +310,7 @@ fn long_box() {
     let box_ = TermBox {
         border_style: BorderStyle::new_double(),
         padding: Padding::none(),
-        lines: str::repeat("Long text ", 3).chars().map(String::from).collect(),
+        lines: str::repeat("Long text ", 3).chars().map(|chr| Line::from(chr.to_string())).collect(),
         ..TermBox::default()
     }.into_string();
 
@@ -151,6 +318,230 @@ fn long_box() {
     assert_matches_template!(box_, "long-box");
 }
 
+#[test]
+fn wrapped() {
+    let box_ = TermBox {
+        padding: Padding::ONE_SPACE,
+        lines: lines![
+            "short",
+            "a line that is much too long for the box",
+            "unbreakablewordthatistoolong"
+        ],
+        max_width: Some(16),
+        ..TermBox::default()
+    }.into_string();
+
+    assert_okay!(lines_same_len(&box_));
+    assert_eq!(box_, "\
+┌──────────────┐
+│ short        │
+│ a line that  │
+│ is much too  │
+│ long for the │
+│ box          │
+│ unbreakablew │
+│ ordthatistoo │
+│ long         │
+└──────────────┘");
+}
+
+#[test]
+fn wrapped_leading_spaces() {
+    let box_ = TermBox {
+        lines: lines![ "  an indent", "          x y" ],
+        max_width: Some(8),
+        ..TermBox::default()
+    }.into_string();
+
+    assert_okay!(lines_same_len(&box_));
+    assert_eq!(box_, "\
+┌──────┐
+│  an  │
+│indent│
+│x y   │
+└──────┘");
+}
+
+#[test]
+fn wrapped_with_ansi_text() {
+    let box_ = TermBox {
+        border_style: BorderStyle::new_single().with_style(Color::Green),
+        padding: Padding::ONE_SPACE,
+        lines: lines![
+            format!("plain {} plain", Color::Red.paint("red text that wraps")),
+            AnsiStrings(&[ BOLD.paint("bold "), Color::Blue.bold().paint("and blue, also wrapping") ])
+        ],
+        max_width: Some(14),
+        ..TermBox::default()
+    }.into_string();
+
+    assert_okay!(lines_same_len(&box_));
+    assert_matches_template!(box_, "wrapped-with-ansi-text");
+}
+
+#[test]
+fn truncated() {
+    let box_ = TermBox {
+        padding: Padding::ONE_SPACE,
+        lines: lines![
+            "short",
+            "a line that is much too long for the box",
+            "exactly fits"
+        ],
+        max_width: Some(16),
+        overflow: Overflow::ELLIPSIS,
+        ..TermBox::default()
+    }.into_string();
+
+    assert_okay!(lines_same_len(&box_));
+    assert_eq!(box_, "\
+┌──────────────┐
+│ short        │
+│ a line that… │
+│ exactly fits │
+└──────────────┘");
+
+    let hidden = 2;
+    let marker = format!("+{hidden}");
+    let box_ = TermBox::default()
+        .with_lines(lines![ "a long line" ])
+        .with_max_width(8)
+        .with_overflow(Overflow::Truncate(marker.into()))
+        .into_string();
+    assert_eq!(box_, "┌──────┐\n│a lo+2│\n└──────┘", "runtime marker");
+}
+
+#[test]
+fn truncated_with_ansi_text() {
+    let box_ = TermBox {
+        border_style: BorderStyle::new_double().with_style(Color::Green),
+        padding: Padding::ONE_SPACE,
+        lines: lines![
+            Color::Red.paint("red text that is cut"),
+            format!("plain then {}", Color::Blue.paint("blue")),
+            AnsiStrings(&[ BOLD.paint("bold"), Color::Yellow.paint(" yellow") ])
+        ],
+        max_width: Some(14),
+        overflow: Overflow::ELLIPSIS,
+        ..TermBox::default()
+    }.into_string();
+
+    assert_okay!(lines_same_len(&box_));
+    assert_matches_template!(box_, "truncated-with-ansi-text");
+}
+
+#[test]
+fn fit_to_terminal() {
+    let box_ = TermBox {
+        padding: Padding::spaces(2),
+        lines: lines![
+            "word ".repeat(100),
+            Color::Red.paint("red ".repeat(100))
+        ],
+        max_width: Some(usize::MAX),
+        ..TermBox::default()
+    }.fit_to_terminal().into_string();
+
+    let width = assert_okay!(lines_same_len(&box_));
+    assert!(width <= terminal_width(), "{width} > {}", terminal_width());
+
+    let titled = TermBox {
+        titles: Titles::none().with_top(Title("title ".repeat(100), TitlePosition::Left)),
+        lines: lines![ Divider::new().with_label(Title("label ".repeat(100), TitlePosition::Right)) ],
+        ..TermBox::default()
+    }.fit_to_terminal().into_string();
+
+    let width = assert_okay!(lines_same_len(&titled), "titled");
+    assert!(width <= terminal_width(), "titled: {width} > {}", terminal_width());
+}
+
+#[test]
+fn aligned() {
+    let box_ = TermBox {
+        padding: Padding::ONE_SPACE,
+        lines: lines![
+            Line::from("centered heading").aligned(Alignment::Centered),
+            "left by default",
+            Line::from("right").aligned(Alignment::Right),
+            Line::from("a centered line that wraps").aligned(Alignment::Centered)
+        ],
+        max_width: Some(21),
+        ..TermBox::default()
+    }.into_string();
+
+    assert_okay!(lines_same_len(&box_));
+    assert_eq!(box_, "\
+┌──────────────────┐
+│ centered heading │
+│ left by default  │
+│            right │
+│ a centered line  │
+│    that wraps    │
+└──────────────────┘");
+}
+
+#[test]
+fn aligned_with_ansi_text() {
+    let box_ = TermBox {
+        border_style: BorderStyle::new_single().with_style(Color::Cyan),
+        padding: Padding::ONE_SPACE,
+        align: Alignment::Right,
+        lines: lines![
+            Line::from(BOLD.paint("Sum").to_string()).aligned(Alignment::Centered),
+            "1,024,000",
+            Color::Red.paint("-12"),
+            Color::Green.paint("1,023,988")
+        ],
+        ..TermBox::default()
+    }.into_string();
+
+    assert_okay!(lines_same_len(&box_));
+    assert_matches_template!(box_, "aligned-with-ansi-text");
+}
+
+#[test]
+fn line_from_ansi_text() {
+    let line = Line::from(format!(
+        "{}{} \x1b[4mund\x1b[24mplain",
+        Color::Fixed(208).bold().paint("fixed"),
+        Color::Rgb(1, 2, 3).on(Color::Black).paint("rgb")
+    ));
+
+    assert_eq!(line.spans(), [
+        Span::new("fixed", Color::Fixed(208).bold()),
+        Span::new("rgb", Color::Rgb(1, 2, 3).on(Color::Black)),
+        Span::plain(" "),
+        Span::new("und", AnsiStyle::new().underline()),
+        Span::plain("plain")
+    ]);
+    assert_eq!(line.width(), 17);
+}
+
+#[test]
+fn spans() {
+    let box_ = TermBox {
+        padding: Padding::ONE_SPACE,
+        lines: vec![
+            Line::from_iter([ Span::plain("status: "), Span::new("degraded", Color::Yellow.bold()) ]),
+            Line::from(Span::new("all spans keep their style when wrapped", Color::Red)),
+        ],
+        max_width: Some(20),
+        ..TermBox::default()
+    }.into_string();
+
+    assert_okay!(lines_same_len(&box_));
+    assert_matches_template!(box_, "spans");
+}
+
+#[test]
+fn empty_lines() {
+    assert!(Line::new().is_empty(), "new");
+    assert!(Line::from("").is_empty(), "no text");
+    assert!(!Line::from(" ").is_empty(), "space");
+    assert!(!Line::from(Divider::new()).is_empty(), "divider");
+    assert!(!Line::from(TermBox::default()).is_empty(), "nested box");
+}
+
 // TODO: necessary test: ANSI-control-only titles (all 3 positions; can be done in 1 func)
 
 #[test]
@@ -159,13 +550,15 @@ fn titles_left() {
         border_style: BorderStyle::new_single(),
         padding: Padding::ONE_SPACE,
         titles: Titles {
-            top: Title("the", TitlePosition::Left),
-            bottom: Title(Color::Red.bold().paint("ever"), TitlePosition::Left)
+            top: vec![Title("the", TitlePosition::Left)],
+            bottom: vec![Title(Color::Red.bold().paint("ever"), TitlePosition::Left)],
+            ..Titles::none()
         },
         lines: lines![
             "coolest",
             "box"
-        ]
+        ],
+        ..TermBox::default()
     }.into_string();
 
     assert_okay!(lines_same_len(&box_));
@@ -178,15 +571,17 @@ fn titles_center() {
         border_style: BorderStyle::new_double(),
         padding: Padding::ONE_SPACE,
         titles: Titles {
-            top: Title(BOLD.paint("center"), TitlePosition::Centered), // Test: even title, odd len
-            bottom: Title(BOLD.paint("of the universe"), TitlePosition::Centered), // Test: odd title, odd len
+            top: vec![Title(BOLD.paint("center"), TitlePosition::Centered)], // Test: even title, odd len
+            bottom: vec![Title(BOLD.paint("of the universe"), TitlePosition::Centered)], // Test: odd title, odd len
+            ..Titles::none()
         },
         lines: lines![
             "the church",
             "viewed the",
             "earth",
             "as the"
-        ]
+        ],
+        ..TermBox::default()
     }.into_string();
 
     assert_okay!(lines_same_len(&box_));
@@ -200,14 +595,16 @@ fn titles_right() {
         border_style: BorderStyle::new_single().with_style(Color::Cyan),
         padding: Padding::none(),
         titles: Titles {
-            top: Title(Color::LightMagenta.paint("Nicolaus"), TitlePosition::Right),
-            bottom: Title(Color::Blue.bold().paint("Copernicus"), TitlePosition::Right)
+            top: vec![Title(Color::LightMagenta.paint("Nicolaus"), TitlePosition::Right)],
+            bottom: vec![Title(Color::Blue.bold().paint("Copernicus"), TitlePosition::Right)],
+            ..Titles::none()
         },
         lines: lines![
             "was censured",
             "for saying",
             "otherwise"
-        ]
+        ],
+        ..TermBox::default()
     }.into_string();
 
     assert_okay!(lines_same_len(&box_));
@@ -221,13 +618,15 @@ fn titles_center_2() {
         border_style: BorderStyle::new_single(),
         padding: Padding::none(),
         titles: Titles {
-            top: Title(BOLD.paint("odd"), TitlePosition::Centered), // Test: odd title, even len
-            bottom: Title(AnsiStyle::new().italic().paint("even"), TitlePosition::Centered) // Test: even title, even len
+            top: vec![Title(BOLD.paint("odd"), TitlePosition::Centered)], // Test: odd title, even len
+            bottom: vec![Title(AnsiStyle::new().italic().paint("even"), TitlePosition::Centered)], // Test: even title, even len
+            ..Titles::none()
         },
         lines: lines![
             "even",
             "widths"
-        ]
+        ],
+        ..TermBox::default()
     }.into_string();
 
     assert_okay!(lines_same_len(&box_));
@@ -236,18 +635,906 @@ fn titles_center_2() {
 }
 
 #[test]
-fn time_example() {
+fn titles_styled() {
+    let box_ = TermBox {
+        border_style: BorderStyle::new_double().with_style(Color::Cyan),
+        padding: Padding::ONE_SPACE,
+        titles: Titles {
+            top: vec![Title("styled", TitlePosition::Centered).with_style(Color::Yellow.bold())],
+            bottom: vec![Title(Color::Red.paint("own codes"), TitlePosition::Right).with_style(AnsiStyle::new().underline())],
+            ..Titles::none()
+        },
+        lines: lines![ "titles keep their", "own style" ],
+        ..TermBox::default()
+    }.into_string();
+
+    assert_okay!(lines_same_len(&box_));
+    assert_matches_template!(box_, "titles-styled");
+}
+
+#[test]
+fn titles_styled_combinations() {
+    const COLORS: &[Rgb] = &[ Rgb::new(0, 0, 0), Rgb::new(255, 255, 255) ];
+    let borders = [
+        BorderStyle::new_single(),
+        BorderStyle::new_single().with_style(Color::Blue.on(Color::Black)),
+        BorderStyle::new_double().with_corner_style(Corner::TopLeft, Color::Fixed(200)),
+        BorderStyle::new_single().with_gradient(BorderGradient::new(COLORS, GradientDirection::Around))
+    ];
+    let titles = [
+        Title("plain", TitlePosition::Left),
+        Title(BOLD.paint("codes"), TitlePosition::Centered),
+        Title("styled", TitlePosition::Right).with_style(Color::Rgb(255, 255, 255).italic()),
+        Title(Color::Red.paint("both"), TitlePosition::Centered).with_style(Color::Green.on(Color::Rgb(1, 2, 3)))
+    ];
+
+    // Rendering also checks that the buffer estimate for each edge holds
+    for border_style in borders {
+        for title in titles.iter() {
+            let box_ = TermBox {
+                border_style: border_style.clone(),
+                titles: Titles { top: vec![title.clone()], bottom: vec![title.clone()], ..Titles::none() },
+                lines: lines![ "x", "a much longer line" ],
+                ..TermBox::default()
+            }.into_string();
+
+            assert_okay!(lines_same_len(&box_));
+        }
+    }
+}
+
+#[test]
+fn titles_multiple() {
+    let titles_box = |titles: Titles, line: &str| TermBox {
+        titles,
+        lines: lines![ line ],
+        ..TermBox::default()
+    }.into_string();
+
+    let all = Titles::none()
+        .with_top(Title("left", TitlePosition::Left))
+        .with_top(Title("mid", TitlePosition::Centered))
+        .with_top(Title("right", TitlePosition::Right))
+        .with_bottom(Title("a", TitlePosition::Right))
+        .with_bottom(Title("b", TitlePosition::Right))
+        .with_bottom(Title("c", TitlePosition::Left));
+    let crowded = Titles::none()
+        .with_top(Title("long left title", TitlePosition::Left))
+        .with_top(Title("mid", TitlePosition::Centered))
+        .with_bottom(Title(Color::Red.paint("styled"), TitlePosition::Centered))
+        .with_bottom(Title("too", TitlePosition::Centered));
+
+    assert_eq!(titles_box(all.clone(), "x"), "┌left─mid─right┐\n│x             │\n└─c────────a─b─┘", "fit");
+    assert_eq!(titles_box(all, &"x".repeat(20)), "\
+┌─left────mid──right─┐
+│xxxxxxxxxxxxxxxxxxxx│
+└─c──────────────a─b─┘", "wide");
+
+    let crowded = titles_box(crowded, &"x".repeat(22));
+    assert_okay!(lines_same_len(&crowded));
+    assert_eq!(crowded.lines().next(), Some("┌─long left title─mid──┐"), "centered moved aside");
+}
+
+#[test]
+fn titles_sides() {
+    let meter = TermBox {
+        titles: Titles::none()
+            .with_left(Title("CPU", TitlePosition::TOP))
+            .with_right(Title("%", TitlePosition::BOTTOM)),
+        lines: lines![ "9", "7", "5", "3", "1" ],
+        ..TermBox::default()
+    }.into_string();
+
+    let grown = TermBox {
+        border_style: BorderStyle::new_single().with_side(Side::Left, None),
+        titles: Titles::none()
+            .with_left(Title("tall", TitlePosition::Centered))
+            .with_right(Title("ｗ", TitlePosition::Centered)),
+        lines: lines![ "x" ],
+        ..TermBox::default()
+    }.into_string();
+
+    assert_eq!(meter, "\
+┌─┐
+│9│
+C7│
+P5│
+U3%
+│1│
+└─┘", "meter");
+    assert_eq!(grown, " ─ ┐\ntx │\na  │\nl ｗ\nl  │\n ─ ┘", "grown");
+}
+
+#[test]
+fn titles_sides_styled() {
+    let box_ = TermBox {
+        border_style: BorderStyle::new_double().with_style(Color::Blue),
+        padding: Padding::ONE_SPACE.with_vertical(1),
+        titles: Titles::none()
+            .with_top(Title("top", TitlePosition::Centered))
+            .with_left(Title(Color::Red.paint("hot"), TitlePosition::TOP))
+            .with_right(Title("cold", TitlePosition::BOTTOM).with_style(Color::Cyan.bold())),
+        lines: lines![ "temperatures" ],
+        ..TermBox::default()
+    }.into_string();
+
+    assert_okay!(lines_same_len(&box_));
+    assert_matches_template!(box_, "titles-sides-styled");
+}
+
+#[test]
+fn titles_overflow() {
+    let overflow_box = |title_overflow| TermBox {
+        padding: Padding::ONE_SPACE,
+        titles: Titles::none()
+            .with_top(Title("short", TitlePosition::Left))
+            .with_top(Title("a much longer title", TitlePosition::Right))
+            .with_bottom(Title("bottom title, centered", TitlePosition::Centered)),
+        lines: lines![ "a fixed-width panel" ],
+        max_width: Some(16),
+        title_overflow,
+        ..TermBox::default()
+    }.into_string();
+
+    assert_eq!(overflow_box(TitleOverflow::ELLIPSIS), "\
+┌short─a much …┐
+│ a            │
+│ fixed-width  │
+│ panel        │
+└bottom title,…┘", "truncated");
+    assert_eq!(overflow_box(TitleOverflow::Truncate(String::from("..").into())), "\
+┌short─a much..┐
+│ a            │
+│ fixed-width  │
+│ panel        │
+└bottom title..┘", "runtime marker");
+    assert_eq!(overflow_box(TitleOverflow::Wrap), "\
+┌──────────────┐
+│ short        │
+│       a much │
+│ longer title │
+│ a            │
+│ fixed-width  │
+│ panel        │
+│    bottom    │
+│    title,    │
+│   centered   │
+└──────────────┘", "wrapped");
+
+    let limited_box = |title: &str, lines: Vec<Line>, max_width, title_overflow| TermBox {
+        titles: Titles::none().with_top(Title(title, TitlePosition::Left)),
+        lines,
+        max_width,
+        title_overflow,
+        ..TermBox::default()
+    }.into_string();
+
+    assert_eq!(limited_box("a long title", lines![ "a" ], Some(6), TitleOverflow::ELLIPSIS), "\
+┌a l…┐
+│a   │
+└────┘", "truncated to the max width");
+    assert_eq!(limited_box("fits", lines![ "a" ], Some(12), TitleOverflow::ELLIPSIS), "\
+┌fits┐
+│a   │
+└────┘", "widened up to the max width");
+    assert_eq!(limited_box("wrap me", lines![ "a" ], Some(6), TitleOverflow::Wrap), "\
+┌────┐
+│wrap│
+│me  │
+│a   │
+└────┘", "wrapped to the max width");
+    assert_eq!(limited_box("sixteen columns!", lines![], None, TitleOverflow::Wrap), "\
+┌sixteen columns!┐
+└────────────────┘", "no max width");
+}
+
+#[test]
+fn titles_overflow_with_ansi_text() {
+    let box_ = TermBox {
+        border_style: BorderStyle::new_single().with_style(Color::Blue),
+        titles: Titles::none()
+            .with_top(Title(Color::Red.paint("red and long"), TitlePosition::Left))
+            .with_top(Title("styled", TitlePosition::Right).with_style(Color::Green.bold()))
+            .with_bottom(Title(BOLD.paint("wrapped title"), TitlePosition::Centered).with_style(Color::Yellow)),
+        lines: lines![ "narrow", "box" ],
+        max_width: Some(8),
+        title_overflow: TitleOverflow::ELLIPSIS,
+        ..TermBox::default()
+    };
+    let wrapped = TermBox { title_overflow: TitleOverflow::Wrap, ..box_.clone() }.into_string();
+    let truncated = box_.into_string();
+
+    assert_okay!(lines_same_len(&truncated), "truncated");
+    assert_okay!(lines_same_len(&wrapped), "wrapped");
+    assert_matches_template!(format!("{truncated}\n{wrapped}"), "titles-overflow-with-ansi-text");
+}
+
+#[test]
+fn titles_decorated() {
+    let decorated_box = |border_style: BorderStyle, decoration: TitleDecoration| TermBox {
+        border_style,
+        titles: Titles::none()
+            .with_top(Title("build", TitlePosition::Left).with_decoration(decoration.clone()).with_spacing(1))
+            .with_bottom(Title("ok", TitlePosition::Right).with_decoration(decoration)),
+        lines: lines![ "compiling crate" ],
+        ..TermBox::default()
+    }.into_string();
+
+    assert_eq!(decorated_box(BorderStyle::new_single(), TitleDecoration::Connectors), "\
+┌─┤ build ├─────┐
+│compiling crate│
+└──────────┤ok├─┘", "single");
+    assert_eq!(decorated_box(BorderShape::Heavy.into(), TitleDecoration::Connectors), "\
+┏━┥ build ┝━━━━━┓
+┃compiling crate┃
+┗━━━━━━━━━━┥ok┝━┛", "heavy");
+    assert_eq!(decorated_box(BorderShape::Ascii.into(), TitleDecoration::Connectors), "\
++-| build |-----+
+|compiling crate|
++----------|ok|-+", "ascii");
+    assert_eq!(decorated_box(BorderStyle::new_double(), TitleDecoration::Brackets("<".into(), ">".into())), "\
+╔═< build >═════╗
+║compiling crate║
+╚══════════<ok>═╝", "brackets");
+    assert_eq!(decorated_box(BorderStyle::new_double().with_side(Side::Bottom, None), TitleDecoration::Connectors), "\
+╔═╡ build ╞═════╗
+║compiling crate║
+             ok  ", "hidden bottom");
+    assert_eq!(decorated_box(BorderStyle::new_single(), TitleDecoration::None), "\
+┌─ build ───────┐
+│compiling crate│
+└────────────ok─┘", "spacing only");
+
+    let truncated = TermBox {
+        titles: Titles::none().with_top(Title("a long title", TitlePosition::Left).with_decoration(TitleDecoration::Connectors)),
+        lines: lines![ "narrow" ],
+        max_width: Some(8),
+        title_overflow: TitleOverflow::ELLIPSIS,
+        ..TermBox::default()
+    }.into_string();
+    assert_eq!(truncated, "┌┤a l…├┐\n│narrow│\n└──────┘", "truncated");
+}
+
+#[test]
+fn titles_decorated_styled() {
+    const COLORS: &[Rgb] = &[ Rgb::new(255, 0, 0), Rgb::new(0, 0, 255) ];
+    let box_ = TermBox {
+        border_style: BorderStyle::new_double().with_style(Color::Blue),
+        titles: Titles::none()
+            .with_top(Title("Build", TitlePosition::Centered)
+                .with_style(Color::Black.on(Color::Green))
+                .with_decoration(TitleDecoration::Connectors)
+                .with_spacing(1))
+            .with_bottom(Title(BOLD.paint("3/3"), TitlePosition::Right).with_decoration(TitleDecoration::SQUARE_BRACKETS)),
+        lines: lines![ "all targets passed" ],
+        padding: Padding::ONE_SPACE,
+        ..TermBox::default()
+    };
+    let gradient = TermBox {
+        border_style: BorderStyle::new_single().with_gradient(BorderGradient::new(COLORS, GradientDirection::Horizontal)),
+        ..box_.clone()
+    };
+    let styled = box_.into_string();
+    let gradient = gradient.into_string();
+
+    assert_okay!(lines_same_len(&styled), "styled");
+    assert_okay!(lines_same_len(&gradient), "gradient");
+    assert_matches_template!(format!("{styled}\n{gradient}"), "titles-decorated-styled");
+}
+
+#[test]
+fn titles_anchored() {
+    let anchored_box = |titles: Titles, lines: Vec<Line>| TermBox { titles, lines, ..TermBox::default() }.into_string();
+
+    let grown = anchored_box(Titles::none().with_top(Title("t", TitlePosition::Left).at_column(6)), lines![ "x" ]);
+    let right = anchored_box(Titles::none().with_bottom(Title("end", TitlePosition::Right).with_offset(0)), lines![ "numbers" ]);
+    let overlapping = anchored_box(Titles::none()
+        .with_top(Title("hello", TitlePosition::Left))
+        .with_top(Title("abc", TitlePosition::Centered).at_column(2))
+        .with_top(Title("z", TitlePosition::Right)), lines![ "0123456789" ]);
+    let sides = anchored_box(Titles::none()
+        .with_left(Title("ab", TitlePosition::TOP).with_offset(2))
+        .with_right(Title("c", TitlePosition::BOTTOM).with_offset(0)), lines![ "1", "2", "3", "4", "5" ]);
+    let truncated = TermBox {
+        titles: Titles::none().with_top(Title("long title", TitlePosition::Left).at_column(3)),
+        lines: lines![ "short" ],
+        max_width: Some(7),
+        title_overflow: TitleOverflow::ELLIPSIS,
+        ..TermBox::default()
+    }.into_string();
+
+    assert_eq!(grown, "┌──────t┐\n│x      │\n└───────┘", "grown");
+    assert_eq!(right, "┌───────┐\n│numbers│\n└────end┘", "right");
+    assert_eq!(overlapping, "┌──abc───z─┐\n│0123456789│\n└──────────┘", "overlapping");
+    assert_eq!(sides, "┌─┐\n│1│\n│2│\na3│\nb4│\n│5c\n└─┘", "sides");
+    assert_eq!(truncated, "┌─────┐\n│short│\n└─────┘", "truncated");
+}
+
+#[test]
+fn dividers() {
+    let divided_box = |border_style: BorderStyle, divider: Divider| TermBox {
+        border_style,
+        padding: Padding::ONE_SPACE,
+        lines: lines![ "above", divider, "below" ],
+        ..TermBox::default()
+    }.into_string();
+
+    let single = divided_box(BorderStyle::new_single(), Divider::new());
+    let double = divided_box(BorderStyle::new_double(), Divider::new());
+    let mixed = divided_box(BorderStyle::new_double(), Divider::new().with_shape(BorderShape::Heavy));
+    let ascii = divided_box(BorderShape::Ascii.into(), Divider::new());
+    let open = divided_box(BorderStyle::new_single().with_side(Side::Right, None), Divider::new().with_shape(BorderShape::Double));
+    let labeled = divided_box(BorderStyle::new_single(), Divider::new()
+        .with_label(Title("section two", TitlePosition::Centered).with_decoration(TitleDecoration::Connectors)));
+
+    assert_eq!(single, "┌───────┐\n│ above │\n├───────┤\n│ below │\n└───────┘", "single");
+    assert_eq!(double, "╔═══════╗\n║ above ║\n╠═══════╣\n║ below ║\n╚═══════╝", "double");
+    assert_eq!(mixed, "╔═══════╗\n║ above ║\n┏━━━━━━━┓\n║ below ║\n╚═══════╝", "mixed w/o junctions");
+    assert_eq!(ascii, "+-------+\n| above |\n+-------+\n| below |\n+-------+", "ascii");
+    assert_eq!(open, "┌───────\n│ above \n╞═══════\n│ below \n└───────", "open");
+    assert_eq!(labeled, "┌───────────────┐\n│ above         │\n├─┤section two├─┤\n│ below         │\n└───────────────┘", "labeled");
+
+    let overflowing = |title_overflow| TermBox {
+        lines: lines![ "a", Divider::new().with_label(Title("a very long label", TitlePosition::Left)), "b" ],
+        max_width: Some(6),
+        title_overflow,
+        ..TermBox::default()
+    }.into_string();
+    assert_eq!(overflowing(TitleOverflow::ELLIPSIS), "┌────┐\n│a   │\n├a v…┤\n│b   │\n└────┘", "truncated label");
+    assert_eq!(overflowing(TitleOverflow::Wrap), "┌────┐\n│a   │\n├────┤\n│a   │\n│very│\n│long│\n│labe│\n│l   │\n│b   │\n└────┘", "wrapped label");
+}
+
+#[test]
+fn dividers_styled() {
+    let box_ = TermBox {
+        border_style: BorderStyle::new_single().with_style(Color::Blue).with_side_style(Side::Left, Color::Green),
+        titles: Titles::none().with_right(Title("xyz", TitlePosition::TOP)),
+        lines: lines![
+            "first",
+            Divider::new(),
+            "second",
+            Divider::new().with_style(Color::Red).with_label(Title("third", TitlePosition::Left).with_style(*BOLD)),
+            "last"
+        ],
+        ..TermBox::default()
+    }.into_string();
+
+    assert_okay!(lines_same_len(&box_));
+    assert_matches_template!(box_, "dividers-styled");
+}
+
+#[test]
+fn table() {
+    let table = |border_style: BorderStyle| Table::new()
+        .with_border_style(border_style)
+        .with_header(lines![ "id", "name", Line::from("ok").aligned(Alignment::Centered) ])
+        .with_row(lines![ 1, "alpha", "yes" ])
+        .with_row(lines![ 22, "b" ])
+        .with_align(0, Alignment::Right)
+        .into_box()
+        .into_string();
+
+    assert_eq!(table(BorderStyle::new_single()), "\
+┌────┬───────┬─────┐
+│ id │ name  │ ok  │
+├────┼───────┼─────┤
+│  1 │ alpha │ yes │
+│ 22 │ b     │     │
+└────┴───────┴─────┘", "single");
+    assert_eq!(table(BorderStyle::new_double()), "\
+╔════╦═══════╦═════╗
+║ id ║ name  ║ ok  ║
+╠════╬═══════╬═════╣
+║  1 ║ alpha ║ yes ║
+║ 22 ║ b     ║     ║
+╚════╩═══════╩═════╝", "double");
+    assert_eq!(table(BorderShape::Heavy.into()), "\
+┏━━━━┳━━━━━━━┳━━━━━┓
+┃ id ┃ name  ┃ ok  ┃
+┣━━━━╋━━━━━━━╋━━━━━┫
+┃  1 ┃ alpha ┃ yes ┃
+┃ 22 ┃ b     ┃     ┃
+┗━━━━┻━━━━━━━┻━━━━━┛", "heavy");
+    assert_eq!(table(BorderShape::Ascii.into()), "\
++----+-------+-----+
+| id | name  | ok  |
++----+-------+-----+
+|  1 | alpha | yes |
+| 22 | b     |     |
++----+-------+-----+", "ascii");
+
+    let no_header = Table::new()
+        .with_separator(BorderShape::Double)
+        .with_cell_padding(0)
+        .with_row(lines![ "a", "b", "c" ])
+        .into_box();
+    let mut divided = Table::new().with_row(lines![ "a", "b" ]).with_row(lines![ "c", "d" ]).into_box();
+    divided.lines.insert(1, Line::from(Divider::new().with_shape(BorderShape::Heavy)));
+    divided.lines.push(Line::from(Divider::new()));
+    divided.lines.push(Line::from("wide footer"));
+
+    assert_eq!(no_header.into_string(), "┌─╥─╥─┐\n│a║b║c│\n└─╨─╨─┘", "no header");
+    assert_eq!(divided.into_string(), "\
+┌───┬───────┐
+│ a │ b     │
+┝━━━┿━━━━━━━┥
+│ c │ d     │
+├───┴───────┤
+│wide footer│
+└───────────┘", "divided");
+    assert_eq!(Table::new().into_box().into_string(), "┌─┐\n└─┘", "empty");
+}
+
+#[test]
+fn table_max_width() {
+    let box_ = TermBox {
+        max_width: Some(10),
+        ..Table::new()
+            .with_header(lines![ "one", "two" ])
+            .with_row(lines![ "alpha", "beta gamma" ])
+            .into_box()
+    };
+
+    assert_eq!(box_.into_string(), "\
+┌───────┬────────────┐
+│ one   │ two        │
+├───────┼────────────┤
+│ alpha │ beta gamma │
+└───────┴────────────┘");
+}
+
+#[test]
+#[should_panic(expected = "table cells must be text")]
+fn table_nested_cell() {
+    let _ = Table::new().with_row(lines![ "a", TermBox::default().with_lines(lines![ "b" ]) ]);
+}
+
+#[test]
+#[should_panic(expected = "table cells must be text")]
+fn table_divider_cell() {
+    let _ = Table::new().with_header(lines![ "a", Divider::new() ]);
+}
+
+#[test]
+fn table_styled() {
+    let box_ = Table::new()
+        .with_border_style(BorderStyle::new_single().with_style(Color::Blue))
+        .with_header(lines![ BOLD.paint("status"), BOLD.paint("count") ])
+        .with_header_divider(Divider::new().with_shape(BorderShape::Double).with_style(Color::Cyan))
+        .with_row(lines![ Color::Green.paint("passed"), 40 ])
+        .with_row(lines![ Color::Red.paint("failed"), 2 ])
+        .with_align(1, Alignment::Right)
+        .into_box()
+        .into_string();
+
+    assert_okay!(lines_same_len(&box_));
+    assert_matches_template!(box_, "table-styled");
+}
+
+#[test]
+fn key_values() {
+    let pairs = || KeyValues::new()
+        .with_pair("os", "linux")
+        .with_pair("kernel", "6.1.0")
+        .with_pair("uptime", "3 days, 4 hours, 12 minutes");
+
+    let plain = pairs().into_box().into_string();
+    let custom = pairs().with_separator(" =").with_gap(2).with_key_align(Alignment::Centered).into_box().into_string();
+    let bare = pairs().with_separator("").with_text_width(20).into_box().into_string();
+
+    assert_eq!(plain, "\
+┌───────────────────────────────────┐
+│os:     linux                      │
+│kernel: 6.1.0                      │
+│uptime: 3 days, 4 hours, 12 minutes│
+└───────────────────────────────────┘", "plain");
+    assert_eq!(custom, "\
+┌─────────────────────────────────────┐
+│  os =    linux                      │
+│kernel =  6.1.0                      │
+│uptime =  3 days, 4 hours, 12 minutes│
+└─────────────────────────────────────┘", "custom separator");
+    assert_eq!(bare, "\
+┌────────────────┐
+│os     linux    │
+│kernel 6.1.0    │
+│uptime 3 days, 4│
+│       hours, 12│
+│       minutes  │
+└────────────────┘", "bare");
+    assert_eq!(KeyValues::new().into_box().into_string(), "┌─┐\n└─┘", "empty");
+}
+
+#[test]
+fn key_values_styled() {
+    let box_ = TermBox {
+        border_style: BorderStyle::new_single().with_style(Color::Blue),
+        padding: Padding::ONE_SPACE,
+        ..KeyValues::new()
+            .with_pair(BOLD.paint("status"), Color::Green.paint("running"))
+            .with_pair(BOLD.paint("message"), Color::Yellow.paint("waiting on three more workers to start"))
+            .with_separator(Color::DarkGray.paint(" │"))
+            .with_key_align(Alignment::Right)
+            .with_text_width(24)
+            .into_box()
+    }.into_string();
+
+    assert_okay!(lines_same_len(&box_));
+    assert_matches_template!(box_, "key-values-styled");
+}
+
+#[test]
+fn nested() {
+    let leaf = |shape| TermBox {
+        border_style: BorderStyle::from(shape),
+        lines: lines![ "leaf" ],
+        ..TermBox::default()
+    };
+    let middle = TermBox {
+        border_style: BorderStyle::new_double(),
+        lines: lines![ leaf(BorderShape::Heavy), Line::from(leaf(BorderShape::Rounded)).aligned(Alignment::Right) ],
+        ..TermBox::default()
+    };
+    let outer = TermBox {
+        padding: Padding::ONE_SPACE,
+        lines: lines![ "the outer box", Line::from(middle).aligned(Alignment::Centered), "end" ],
+        ..TermBox::default()
+    }.into_string();
+
+    assert_eq!(outer, "\
+┌───────────────┐
+│ the outer box │
+│   ╔══════╗    │
+│   ║┏━━━━┓║    │
+│   ║┃leaf┃║    │
+│   ║┗━━━━┛║    │
+│   ║╭────╮║    │
+│   ║│leaf│║    │
+│   ║╰────╯║    │
+│   ╚══════╝    │
+│ end           │
+└───────────────┘", "three deep");
+
+    let wrapped = TermBox {
+        lines: lines![ TermBox::default().with_lines(lines![ "wrapped inside" ]) ],
+        max_width: Some(10),
+        ..TermBox::default()
+    }.into_string();
+
+    assert_eq!(wrapped, "\
+┌────────┐
+│┌──────┐│
+││wrappe││
+││d     ││
+││inside││
+│└──────┘│
+└────────┘", "limited by the outer box");
+}
+
+#[test]
+fn appended() {
+    let mut appended = TermBox::default().append_with(Line::from("title").aligned(Alignment::Centered));
+    appended.append(Divider::new());
+    appended.append(TermBox::default().with_lines(lines![ "inner box" ]));
+
+    let expected = TermBox::default().with_lines(lines![
+        Line::from("title").aligned(Alignment::Centered),
+        Divider::new(),
+        TermBox::default().with_lines(lines![ "inner box" ])
+    ]);
+    assert_eq!(appended, expected, "same lines");
+    assert_eq!(appended.into_string(), "\
+┌───────────┐
+│   title   │
+├───────────┤
+│┌─────────┐│
+││inner box││
+│└─────────┘│
+└───────────┘");
+
+    let displayed = TermBox::default().append_with(Color::Red.paint("red")).append_with(42).append_with('c');
+    assert_eq!(displayed.into_string(), format!("\
+┌───┐
+│{}│
+│42 │
+│c  │
+└───┘", Color::Red.paint("red")), "displayed values");
+}
+
+#[test]
+fn nested_styled() {
+    let inner = TermBox {
+        border_style: BorderStyle::from(BorderShape::Heavy).with_style(Color::Green),
+        titles: Titles::none().with_top(Title(BOLD.paint("inner"), TitlePosition::Centered)),
+        padding: Padding::ONE_SPACE,
+        lines: lines![ Color::Yellow.paint("styled text in a styled box") ],
+        ..TermBox::default()
+    };
+    let box_ = TermBox {
+        border_style: BorderStyle::from(BorderShape::Rounded).with_style(Color::Blue),
+        titles: Titles::none().with_top(Title("outer", TitlePosition::Left)),
+        padding: Padding::ONE_SPACE,
+        lines: lines![ "before", Line::from(inner).aligned(Alignment::Centered), "after" ],
+        max_width: Some(26),
+        ..TermBox::default()
+    }.into_string();
+
+    assert_okay!(lines_same_len(&box_));
+    assert_matches_template!(box_, "nested-styled");
+}
+
+#[test]
+fn hstack() {
+    let stack = || HStack::new()
+        .with_box(TermBox::default().with_lines(lines![ "a", "b", "c" ]))
+        .with_box(TermBox::default().with_lines(lines![ "tall", "box", "here", "too" ]))
+        .with_box(TermBox::default().with_lines(lines![ "x" ]));
+
+    assert_eq!(stack().into_string(), "\
+┌─┐ ┌────┐ ┌─┐
+│a│ │tall│ │x│
+│b│ │box │ └─┘
+│c│ │here│    
+└─┘ │too │    
+    └────┘    ", "top");
+    assert_eq!(stack().with_gap(0).with_align(VerticalAlignment::Bottom).into_string(), "   ┌────┐   
+┌─┐│tall│   
+│a││box │   
+│b││here│┌─┐
+│c││too ││x│
+└─┘└────┘└─┘", "bottom with no gap");
+    assert_eq!(HStack::new().into_string(), "", "empty");
+}
+
+#[test]
+fn hstack_styled() {
+    let panel = |name, status: String, color: Color| TermBox {
+        border_style: BorderStyle::new_double().with_style(color),
+        titles: Titles::none().with_top(Title(BOLD.paint(name), TitlePosition::Centered)),
+        padding: Padding::ONE_SPACE,
+        lines: lines![ status ],
+        ..TermBox::default()
+    };
+    let stack = HStack::new()
+        .with_box(panel("web", Color::Green.paint("up").to_string(), Color::Green))
+        .with_box(Table::new().with_header(lines![ "queue", "jobs" ]).with_row(lines![ "mail", Color::Red.paint("42") ]))
+        .with_box(panel("database", Color::Yellow.paint("degraded").to_string(), Color::Yellow))
+        .with_gap(2)
+        .with_align(VerticalAlignment::Middle)
+        .into_string();
+
+    assert_okay!(lines_same_len(&stack));
+    assert_matches_template!(stack, "hstack-styled");
+}
+
+#[test]
+fn grid() {
+    let cell = |text: &str, border_style: BorderStyle| TermBox {
+        border_style,
+        lines: lines![ text ],
+        ..TermBox::default()
+    };
+    let single = || BorderStyle::new_single();
+    let double_sides = || BorderStyle::new_single().with_side(Side::Left, BorderShape::Double).with_side(Side::Right, BorderShape::Double);
+    let double_ends = || BorderStyle::new_single().with_side(Side::Top, BorderShape::Double).with_side(Side::Bottom, BorderShape::Double);
+
+    let uniform = Grid::new()
+        .with_row(vec![cell("a", single()), cell("bb", single()), cell("c", single())])
+        .with_row(vec![cell("ddd", single()), cell("e", single())])
+        .into_string();
+    let mixed = Grid::new()
+        .with_row(vec![cell("a", double_sides()), cell("b", double_sides())])
+        .with_row(vec![cell("c", double_sides()), cell("d", double_sides())])
+        .into_string();
+    let crossed = Grid::new()
+        .with_row(vec![cell("a", double_ends()), cell("b", double_ends())])
+        .with_row(vec![cell("c", double_ends()), cell("d", double_ends())])
+        .into_string();
+    let hidden = Grid::new()
+        .with_row(vec![cell("a", single().with_side(Side::Right, None)), cell("b", single())])
+        .into_string();
+
+    assert_eq!(uniform, "\
+┌───┬──┬─┐
+│a  │bb│c│
+├───┼──┼─┘
+│ddd│e │  
+└───┴──┘  ", "single");
+    assert_eq!(mixed, "\
+╓─╥─╖
+║a║b║
+╟─╫─╢
+║c║d║
+╙─╨─╜", "double sides");
+    assert_eq!(crossed, "\
+╒═╤═╕
+│a│b│
+╞═╪═╡
+│c│d│
+╘═╧═╛", "double ends");
+    assert_eq!(hidden, "\
+┌─┌─┐
+│a│b│
+└─└─┘", "hidden side");
+    assert_eq!(Grid::new().into_string(), "", "empty");
+}
+
+#[test]
+fn grid_styled() {
+    let panel = |name, lines: Vec<Line>, border_style: BorderStyle| TermBox {
+        border_style,
+        titles: Titles::none().with_top(Title(BOLD.paint(name), TitlePosition::Left)),
+        padding: Padding::ONE_SPACE,
+        lines,
+        ..TermBox::default()
+    };
+    let grid = Grid::new()
+        .with_row(vec![
+            panel("cpu", lines![ Color::Green.paint("12%") ], BorderStyle::new_single().with_style(Color::Blue)),
+            panel("memory", lines![ Color::Yellow.paint("3.2 GiB"), "of 16 GiB" ], BorderStyle::new_single().with_style(Color::Blue))
+        ])
+        .with_row(vec![
+            panel("disk", lines![ "412 GiB free" ], BorderStyle::new_double().with_style(Color::Cyan)),
+            Table::new()
+                .with_row(lines![ "eth0", Color::Green.paint("up") ])
+                .with_row(lines![ "wlan0", Color::Red.paint("down") ])
+                .into_box()
+        ])
+        .into_string();
+
+    assert_okay!(lines_same_len(&grid));
+    assert_matches_template!(grid, "grid-styled");
+}
+
+#[test]
+fn canvas() {
+    let mut canvas = Canvas::new();
+    canvas.draw_box(0, 0, TermBox::default().with_lines(lines![ "under", "here" ]));
+    canvas.draw_box(3, 1, TermBox { border_style: BorderStyle::from(BorderShape::Heavy), ..TermBox::default().with_lines(lines![ "top" ]) });
+    canvas.draw_box(0, 4, TermBox { border_style: BorderStyle::from(BorderShape::Rounded), ..TermBox::default() });
+
+    assert_eq!((canvas.width(), canvas.height()), (8, 6), "size");
+    assert_eq!(canvas.cell(3, 1).map(Cell::text), Some("┏"), "merged cell");
+    assert_eq!(canvas.cell(0, 6), None, "outside");
+    assert_eq!(canvas.into_string(), "\
+┌─────┐ 
+│un┏━━┿┓
+│he┃top┃
+└──┺━━┷┛
+╭─╮     
+╰─╯     ", "overlapping");
+
+    let mut wide = Canvas::new();
+    wide.draw_text(0, 0, "界界e\u{301}");
+    wide.draw_text(1, 0, "ab");
+    assert_eq!(wide.cell(4, 0).map(Cell::text), Some("e\u{301}"), "combining");
+    assert_eq!(wide.into_string(), " ab e\u{301}", "partly covered wide characters");
+    assert_eq!(Canvas::new().into_string(), "", "empty");
+}
+
+#[test]
+fn canvas_styled() {
+    let node = |text, color: Color| TermBox {
+        border_style: BorderStyle::new_single().with_style(color),
+        padding: Padding::ONE_SPACE,
+        lines: lines![ AnsiStyle::new().fg(color).bold().paint(text) ],
+        ..TermBox::default()
+    };
+    let mut canvas = Canvas::new();
+    canvas.draw_box(0, 0, node("source", Color::Green));
+    canvas.draw_box(8, 2, node("transform", Color::Yellow));
+    canvas.draw_box(20, 0, TermBox {
+        border_style: BorderStyle::new_double().with_style(Color::Red),
+        titles: Titles::none().with_top(Title("sink", TitlePosition::Centered)),
+        lines: lines![ "", Color::DarkGray.paint("waiting"), "" ],
+        ..TermBox::default()
+    });
+    canvas.draw_text(1, 5, AnsiStyle::new().italic().paint("a pipeline"));
+    let canvas = canvas.into_string();
+
+    assert_okay!(lines_same_len(&canvas));
+    assert_matches_template!(canvas, "canvas-styled");
+}
+
+#[test]
+fn connectors() {
+    let node = |text: &str, shape: BorderShape| TermBox { border_style: BorderStyle::from(shape), ..TermBox::default().with_lines(lines![ text ]) };
+
+    let mut canvas = Canvas::new();
+    let a = canvas.draw_box(0, 0, node("a", BorderShape::Heavy));
+    let b = canvas.draw_box(10, 4, node("b", BorderShape::Single));
+    let c = canvas.draw_box(0, 6, node("c", BorderShape::Double));
+    canvas.draw_connector(&Connector::new(&a, Side::Right, &b, Side::Top).with_label("to b"));
+    canvas.draw_connector(&Connector::new(&a, Side::Bottom, &c, Side::Top).with_arrow(false).with_label("down"));
+    canvas.draw_connector(&Connector::new(&c, Side::Right, &b, Side::Bottom).with_shape(BorderShape::Rounded));
+
+    assert_eq!(canvas.into_string(), "\
+┏━┓          
+┃a┣━━to b━━┓ 
+┗┳┛        ┃ 
+ ┃         ▼ 
+ ┃ down   ┌─┐
+ ┃        │b│
+╔╩╗       └─┘
+║c╟────╮   ▲ 
+╚═╝    ╰───╯ ", "routes");
+
+    let mut ascii = Canvas::new();
+    let a = ascii.draw_box(0, 2, node("a", BorderShape::Ascii));
+    let b = ascii.draw_box(8, 3, node("b", BorderShape::Ascii));
+    ascii.draw_connector(&Connector::new(&b, Side::Bottom, &a, Side::Bottom));
+    ascii.draw_connector(&Connector::new(&a, Side::Top, &b, Side::Top).with_label("via"));
+    assert_eq!(ascii.into_string(), " +--via--+ 
+ |       | 
++-+      v 
+|a|     +-+
++-+     |b|
+ ^      +-+
+ |       | 
+ +-------+ ", "ascii");
+
+    let mut clipped = Canvas::new();
+    let a = clipped.draw_box(0, 0, node("a", BorderShape::Single));
+    let b = clipped.draw_box(6, 0, node("b", BorderShape::Single));
+    assert!(!clipped.draw_connector(&Connector::new(&a, Side::Top, &b, Side::Top)), "drawn above the canvas");
+    assert!(!clipped.draw_connector(&Connector::new(&a, Side::Left, &b, Side::Left)), "drawn left of the canvas");
+    assert_eq!(clipped.into_string(), "\
+┌─┐   ┌─┐
+│a│   │b│
+└─┘   └─┘", "nothing drawn");
+
+    let mut labelled = Canvas::new();
+    let a = labelled.draw_box(4, 0, node("a", BorderShape::Single));
+    let b = labelled.draw_box(9, 0, node("b", BorderShape::Single));
+    let c = labelled.draw_box(4, 6, node("c", BorderShape::Single));
+    labelled.draw_box(7, 3, node("d", BorderShape::Single));
+    labelled.draw_connector(&Connector::new(&a, Side::Right, &b, Side::Left).with_label("label"));
+    labelled.draw_connector(&Connector::new(&a, Side::Bottom, &c, Side::Top).with_label("ok"));
+    assert_eq!(labelled.into_string(), "    ┌─┐  ┌─┐
+    │a├─▶│b│
+    └┬┘  └─┘
+     │ ┌─┐  
+  ok │ │d│  
+     ▼ └─┘  
+    ┌─┐     
+    │c│     
+    └─┘     ", "labels clear of boxes");
+}
+
+#[test]
+fn connectors_styled() {
+    let stage = |name: &str, color: Color| TermBox {
+        border_style: BorderStyle::new_single().with_style(color),
+        padding: Padding::ONE_SPACE,
+        lines: lines![ BOLD.paint(name) ],
+        ..TermBox::default()
+    };
+    let mut canvas = Canvas::new();
+    let fetch = canvas.draw_box(0, 0, stage("fetch", Color::Blue));
+    let build = canvas.draw_box(20, 0, stage("build", Color::Yellow));
+    let deploy = canvas.draw_box(19, 5, TermBox { border_style: BorderStyle::new_double().with_style(Color::Green), ..stage("deploy", Color::Green) });
+    canvas.draw_connector(&Connector::new(&fetch, Side::Right, &build, Side::Left).with_label(Color::DarkGray.paint("sources")));
+    canvas.draw_connector(&Connector::new(&build, Side::Bottom, &deploy, Side::Top).with_style(Color::Cyan).with_label("on success"));
+    canvas.draw_connector(&Connector::new(&deploy, Side::Left, &fetch, Side::Bottom).with_shape(BorderShape::Heavy).with_style(Color::Red));
+    let canvas = canvas.into_string();
+
+    assert_okay!(lines_same_len(&canvas));
+    assert_matches_template!(canvas, "connectors-styled");
+}
+
+fn time_example_box() -> String {
     use nu_ansi_term::Color;
     use std::time::SystemTime;
 
     let time = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap();
 
-    let time_box = TermBox {
+    TermBox {
         border_style: BorderStyle::new_double().with_style(Color::Cyan),
         padding: Padding::spaces(2),
         titles: Titles {
-            top: Title("Time since unix epoch", TitlePosition::Centered),
-            bottom: Title::empty(),
+            top: vec![Title("Time since unix epoch", TitlePosition::Centered)],
+            bottom: vec![],
+            ..Titles::none()
         },
         lines: lines![
             "",
@@ -257,8 +1544,18 @@ fn time_example() {
             Color::Blue.bold().paint("Irrelevant styled text to show that you can do this"),
             AnsiStyle::new().italic().paint("More styled text to show another way"),
             ""
-        ]
-    }.into_string();
+        ],
+        ..TermBox::default()
+    }.into_string()
+}
+
+#[test]
+fn time_example() {
+    assert_okay!(lines_same_len(&time_example_box()));
+}
 
-    init_template!(time_box, "time-example");
+#[test]
+#[ignore = "regenerates test-input/time-example.txt, which is linked from the readme"]
+fn time_example_template() {
+    init_template!(time_example_box(), "time-example");
 }
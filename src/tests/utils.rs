@@ -1,7 +1,7 @@
 use super::AnsiStyle;
 use derive_new::new;
 use ansi_width::ansi_width;
-use std::cell::LazyCell;
+use std::sync::LazyLock;
 
 macro_rules! assert_okay {
     ($expr:expr $(, $name:literal)?) => {
@@ -49,7 +49,6 @@ pub(crate) struct LineLenErr {
 
 pub(crate) fn lines_same_len(string: &str) -> Result<usize, LineLenErr> {
     string.split('\n')
-        .into_iter()
         .enumerate()
         .try_fold(0, |len, (idx, next)| {
             let next_len = ansi_width(next);
@@ -60,4 +59,4 @@ pub(crate) fn lines_same_len(string: &str) -> Result<usize, LineLenErr> {
         })
 }
 
-pub(crate) const BOLD: LazyCell<AnsiStyle> = LazyCell::new(| | AnsiStyle::new().bold());
+pub(crate) static BOLD: LazyLock<AnsiStyle> = LazyLock::new(| | AnsiStyle::new().bold());
@@ -8,8 +8,9 @@
 //! 
 //! let ex = TermBox {
 //!     titles: Titles {
-//!         top: Title("box", TitlePosition::Centered),
-//!         bottom: Title::empty()
+//!         top: vec![Title("box", TitlePosition::Centered)],
+//!         bottom: vec![],
+//!         ..Titles::none()
 //!     },
 //!     lines: lines![ "... stuff ..." ],
 //!     padding: Padding::ONE_SPACE,
@@ -32,8 +33,9 @@
 //!
 //! let ex = TermBox {
 //!     titles: Titles {
-//!         top: Title("hello", TitlePosition::Left),
-//!         bottom: Title("world!", TitlePosition::Right)
+//!         top: vec![Title("hello", TitlePosition::Left)],
+//!         bottom: vec![Title("world!", TitlePosition::Right)],
+//!         ..Titles::none()
 //!     },
 //!     lines: lines![ "strange" ],
 //!     padding: Padding::spaces(2),
@@ -49,11 +51,19 @@
 //! assert_eq!(ex.into_string(), output.trim());
 //! ```
 
-use super::{CountedString, DEFAULT_DIST_FROM_CORNER, TermBox};
+use super::{
+    Alignment, AnsiStyle, BorderShape, BorderStyle, CountedString, DEFAULT_DIST_FROM_CORNER, Line, Side, Span, TermBox,
+    ansi::{self, Token}, overflow
+};
+use ansi_width::ansi_width;
+use std::{borrow::Cow, cmp};
 
 pub use cons::Title;
 
-/// Represents the horizontal position of a title within the border of the [TermBox].
+/// Represents the position of a title along its edge of the [TermBox].
+///
+/// On the lefthand and righthand sides of the box, titles run downwards, and [TitlePosition::TOP]
+/// and [TitlePosition::BOTTOM] may be used for clarity.
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 pub enum TitlePosition {
     /// Tries to position the title in the center of the box's top/bottom border.
@@ -63,22 +73,69 @@ pub enum TitlePosition {
     /// the title and it will be displayed slightly to the left or right of center.
     Centered,
     /// Tries to position the title on the lefthand side of the box's top/bottom border,
-    /// one character away from the corner.
+    /// one character away from the corner, or as far as its [offset](Title::with_offset).
     #[default]
     Left,
     /// Tries to position the title on the righthand side of the box's top/bottom border,
-    /// one character away from the corner.
+    /// one character away from the corner, or as far as its [offset](Title::with_offset).
     Right
 }
 
+impl From<TitlePosition> for Alignment {
+    fn from(pos: TitlePosition) -> Self {
+        match pos {
+            TitlePosition::Left => Alignment::Left,
+            TitlePosition::Centered => Alignment::Centered,
+            TitlePosition::Right => Alignment::Right
+        }
+    }
+}
+
+impl TitlePosition {
+    /// Tries to position a title on the lefthand or righthand side at the top, one row away from
+    /// the corner. The same as [TitlePosition::Left].
+    pub const TOP: Self = Self::Left;
+    /// Tries to position a title on the lefthand or righthand side at the bottom, one row away from
+    /// the corner. The same as [TitlePosition::Right].
+    pub const BOTTOM: Self = Self::Right;
+}
+
 /// A title displayed in the border of the box itself. See the [module-level docs](../../term_box/title/index.html)
 /// for examples.
 ///
 /// Construct with [Title::empty] or the [Title](cons::Title) function.
+///
+/// A title may be given its own [AnsiStyle] with [with_style](Title::with_style). It is painted
+/// separately from the border, so neither style affects the other. It may also be set apart from
+/// the border with a [TitleDecoration] and [spacing](Title::with_spacing).
+///
+/// # Examples
+///
+/// ```
+/// use term_box::*;
+///
+/// let box_ = TermBox {
+///     border_style: BorderStyle::new_single().with_style(Color::Blue),
+///     titles: Titles {
+///         top: vec![Title("Build", TitlePosition::Left).with_style(Color::Green.bold())],
+///         bottom: vec![],
+///         ..Titles::none()
+///     },
+///     lines: lines![ "passed" ],
+///     ..TermBox::default()
+/// };
+///
+/// let top = box_.into_string().lines().next().unwrap().to_string();
+/// assert_eq!(top, "\x1b[34m┌─\x1b[0m\x1b[1;32mBuild\x1b[0m\x1b[34m┐\x1b[0m");
+/// ```
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Title {
     pub(crate) text: CountedString<'static>,
-    pub(crate) pos: TitlePosition
+    pub(crate) pos: TitlePosition,
+    pub(crate) style: AnsiStyle,
+    pub(crate) decoration: TitleDecoration,
+    pub(crate) spacing: usize,
+    pub(crate) anchor: TitleAnchor
 }
 
 impl Title {
@@ -97,99 +154,574 @@ impl Title {
     /// Returns the title's position.
     pub fn pos(&self) -> TitlePosition { self.pos }
 
-    pub(crate) fn width(&self) -> usize { self.text.width }
+    /// Sets the [AnsiStyle] of the title and returns it.
+    ///
+    /// The style is applied before any escape sequences already in the title's text, which may
+    /// override or reset it.
+    pub fn with_style(self, style: impl Into<AnsiStyle>) -> Self {
+        Self { style: style.into(), ..self }
+    }
 
-    pub(crate) fn len_bytes(&self) -> usize { self.text().len() }
+    /// Returns the title's [AnsiStyle].
+    pub fn style(&self) -> AnsiStyle { self.style }
 
-    pub(crate) fn left_pad_len(&self, total_len: usize) -> usize {
-        let width = self.width();
-        if let Some(pad_len) = special_pad_len(width, total_len) {
-            return pad_len
-        }
+    /// Sets the [TitleDecoration] displayed on either side of the title and returns it.
+    pub fn with_decoration(self, decoration: TitleDecoration) -> Self {
+        Self { decoration, ..self }
+    }
 
-        match self.pos {
-            TitlePosition::Left => DEFAULT_DIST_FROM_CORNER,
-            TitlePosition::Right => opposite_side_pad_len(width, total_len),
-            TitlePosition::Centered => {
-                center_pad_len(width, total_len, 0)
-            }
+    /// Returns the title's [TitleDecoration].
+    pub fn decoration(&self) -> &TitleDecoration { &self.decoration }
+
+    /// Sets the number of spaces between the title's text and its [decoration](Title::with_decoration),
+    /// or the border if it has none, and returns it. The spaces are painted in the title's style.
+    pub fn with_spacing(self, spacing: usize) -> Self {
+        Self { spacing, ..self }
+    }
+
+    /// Returns the number of spaces on either side of the title's text.
+    pub fn spacing(&self) -> usize { self.spacing }
+
+    /// Places the title exactly `offset` columns from the corner its [position](TitlePosition) is
+    /// on, or rows for titles on the lefthand and righthand sides, and returns it.
+    /// [Centered](TitlePosition::Centered) titles are not affected.
+    pub fn with_offset(self, offset: usize) -> Self {
+        Self { anchor: TitleAnchor::Offset(offset), ..self }
+    }
+
+    /// Places the title at exactly the given column, counting from the inside of the lefthand
+    /// corner as for the box's lines, whatever its [position](TitlePosition), and returns it. For titles
+    /// on the lefthand and righthand sides, this is the row counting from the inside of the top corner.
+    pub fn at_column(self, column: usize) -> Self {
+        Self { anchor: TitleAnchor::Column(column), ..self }
+    }
+
+    /// Returns where the title is anchored along its edge.
+    pub fn anchor(&self) -> TitleAnchor { self.anchor }
+
+    /// Returns the length of the inside of an edge needed to fit the title where it is anchored,
+    /// if it is anchored, given its length along the edge.
+    fn anchored_len(&self, len: usize) -> Option<usize> {
+        match (self.anchor, self.pos) {
+            (TitleAnchor::Column(start), _) => Some(start + len),
+            (TitleAnchor::Offset(_), TitlePosition::Centered) | (TitleAnchor::Flow, _) => None,
+            (TitleAnchor::Offset(offset), _) => Some(offset + len)
         }
     }
 
-    pub(crate) fn right_pad_len(&self, total_len: usize) -> usize {
-        let width = self.width();
-        if let Some(pad_len) = special_pad_len(width, total_len) {
-            return pad_len
+    /// Returns where the title starts on an edge `inner` long, if it is anchored, given its length along the edge.
+    fn anchored_start(&self, inner: usize, len: usize) -> Option<usize> {
+        match (self.anchor, self.pos) {
+            (TitleAnchor::Offset(offset), TitlePosition::Right) => Some(inner.saturating_sub(offset + len)),
+            (TitleAnchor::Offset(offset), TitlePosition::Left) => Some(offset),
+            (TitleAnchor::Column(start), _) => Some(start),
+            _ => None
         }
+    }
 
-        match self.pos {
-            TitlePosition::Right => DEFAULT_DIST_FROM_CORNER,
-            TitlePosition::Left => opposite_side_pad_len(width, total_len),
-            TitlePosition::Centered => {
-                center_pad_len(width, total_len, 1)
+    /// Returns the number of columns the title takes up on the top or bottom edge, including its
+    /// decoration and spacing.
+    pub(crate) fn width(&self) -> usize {
+        let (before, after) = self.decoration.ends();
+        ansi_width(&before) + self.text_width() + 2 * self.spacing + ansi_width(&after)
+    }
+
+    /// Returns the width of the title's text alone.
+    pub(crate) fn text_width(&self) -> usize { self.text.width }
+
+    /// Returns the title's text with its spacing on either side.
+    pub(crate) fn spaced_text(&self) -> String {
+        let spaces = " ".repeat(self.spacing);
+        format!("{spaces}{}{spaces}", self.text())
+    }
+
+    /// Returns the number of rows the title takes up on a lefthand or righthand side, which is one per character.
+    pub(crate) fn height(&self) -> usize {
+        ansi::tokens(self.text()).filter(|token| matches!(token, Token::Char(..))).count()
+    }
+
+    /// Converts the title into a [Line] in the title's style, aligned according to its position.
+    pub(crate) fn to_line(&self) -> Line {
+        let mut line = Line::new().aligned(self.pos.into());
+        let mut style = self.style;
+        for token in ansi::tokens(self.text()) {
+            match token {
+                Token::Escape(escape) if ansi::is_sgr(escape) => ansi::apply_sgr(&mut style, escape),
+                Token::Escape(_) => {},
+                Token::Char(_, str) => line.push_styled(str, style)
             }
         }
+        line
     }
-}
 
-fn special_pad_len(width: usize, total_len: usize) -> Option<usize> {
-    if width == 0 {
-        return Some(total_len / 2);
+    /// Returns the title's characters from top to bottom, for a lefthand or righthand side, each
+    /// in the style it is displayed in.
+    pub(crate) fn vertical_spans(&self) -> Vec<Span> {
+        let mut style = self.style;
+        ansi::tokens(self.text()).filter_map(|token| match token {
+            Token::Escape(escape) => {
+                if ansi::is_sgr(escape) {
+                    ansi::apply_sgr(&mut style, escape)
+                }
+                None
+            },
+            Token::Char(_, str) => Some(Span::new(str, style))
+        }).collect()
     }
 
-    #[cfg(test)]
-    assert!(total_len > width);
+    pub(crate) fn len_bytes(&self) -> usize { self.text().len() + 2 * self.spacing }
+}
 
-    let diff = (total_len - TermBox::SIDES) - width;
-    match diff {
-        0 => Some(0),
-        _ => None
-    }
+/// Where a [Title](struct@Title) is placed along its edge of a [TermBox].
+///
+/// Anchored titles are placed before the others, which are laid out around them as if they weren't
+/// there. Any title that would overlap one placed before it, or run past the end of its edge, is
+/// left out. With [TitleOverflow::Grow], the box is made wide enough for each anchored title.
+///
+/// # Examples
+///
+/// Line titles up with the columns of a table:
+///
+/// ```
+/// use term_box::*;
+///
+/// let table = TermBox {
+///     titles: Titles::none()
+///         .with_top(Title("name", TitlePosition::Left).at_column(1))
+///         .with_top(Title("size", TitlePosition::Left).at_column(12))
+///         .with_bottom(Title("2 files", TitlePosition::Right).with_offset(3)),
+///     padding: Padding::ONE_SPACE,
+///     lines: lines![ "main.rs    4.2 KiB", "readme.md  1.1 KiB" ],
+///     ..TermBox::default()
+/// };
+///
+/// let output = "
+/// ┌─name───────size────┐
+/// │ main.rs    4.2 KiB │
+/// │ readme.md  1.1 KiB │
+/// └──────────2 files───┘
+/// ";
+///
+/// assert_eq!(table.into_string(), output.trim());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TitleAnchor {
+    /// Place the title according to its [TitlePosition], along with the other titles in that position.
+    #[default]
+    Flow,
+    /// Place the title exactly this many columns from the corner its [TitlePosition] is on. See
+    /// [with_offset](Title::with_offset).
+    Offset(usize),
+    /// Place the title at exactly this column. See [at_column](Title::at_column).
+    Column(usize)
 }
 
-fn opposite_side_pad_len(width: usize, total_len: usize) -> usize {
-    total_len - width - DEFAULT_DIST_FROM_CORNER - TermBox::SIDES
+/// The glyphs displayed on either side of a [Title](struct@Title) on the top or bottom edge of a [TermBox], to set
+/// it apart from the border. Titles on the lefthand and righthand sides are not decorated.
+///
+/// Decorations are drawn as part of the border, in the style of the edge they are on.
+///
+/// # Examples
+///
+/// ```
+/// use term_box::*;
+///
+/// let titled_box = |border_style, decoration| TermBox {
+///     border_style,
+///     titles: Titles::none().with_top(Title("Build", TitlePosition::Left).with_decoration(decoration).with_spacing(1)),
+///     lines: lines![ "all targets" ],
+///     ..TermBox::default()
+/// }.into_string();
+///
+/// let single = titled_box(BorderStyle::new_single(), TitleDecoration::Connectors);
+/// let double = titled_box(BorderStyle::new_double(), TitleDecoration::Connectors);
+/// let brackets = titled_box(BorderStyle::new_single(), TitleDecoration::SQUARE_BRACKETS);
+///
+/// assert_eq!(single.lines().next(), Some("┌─┤ Build ├─┐"));
+/// assert_eq!(double.lines().next(), Some("╔═╡ Build ╞═╗"));
+/// assert_eq!(brackets.lines().next(), Some("┌─[ Build ]─┐"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum TitleDecoration {
+    /// Display the title directly against the border.
+    #[default]
+    None,
+    /// Display the given glyphs before and after the title.
+    Brackets(Cow<'static, str>, Cow<'static, str>),
+    /// Join the edge to the title with the junction glyphs matching the edge's [BorderShape], such as
+    /// `┤` and `├` for [Single](BorderShape::Single) or `╡` and `╞` for [Double](BorderShape::Double).
+    /// Shapes without junction glyphs use their righthand and lefthand sides instead.
+    Connectors
 }
 
-fn center_pad_len(width: usize, total_len: usize, parity_diff_mod: usize) -> usize {
-    const ODD: usize = 1;
-    const EVEN: usize = 0;
+impl TitleDecoration {
+    /// Display the title in square brackets: `[` and `]`.
+    pub const SQUARE_BRACKETS: TitleDecoration = TitleDecoration::Brackets(Cow::Borrowed("["), Cow::Borrowed("]"));
 
-    let res = (total_len / 2) - (width / 2) - 1;
-    let total_parity = total_len & 1;
-    if (width & 1) != total_parity {
-        return match total_parity {
-            ODD => res + parity_diff_mod,
-            EVEN => res - parity_diff_mod,
-            _ => panic!("Impossible condition")
+    /// Returns the glyphs displayed before and after the title.
+    ///
+    /// [Connectors](TitleDecoration::Connectors) are resolved against the box's border before it is
+    /// laid out, and are otherwise taken to be [Single](BorderShape::Single).
+    pub(crate) fn ends(&self) -> (Cow<'_, str>, Cow<'_, str>) {
+        match self {
+            Self::None => (Cow::Borrowed(""), Cow::Borrowed("")),
+            Self::Brackets(before, after) => (Cow::Borrowed(before), Cow::Borrowed(after)),
+            Self::Connectors => BorderShape::Single.connectors()
         }
     }
 
-    res
+    /// Replaces [Connectors](TitleDecoration::Connectors) with the glyphs for an edge of the given
+    /// shape, or [None](TitleDecoration::None) if the edge isn't drawn.
+    fn connect(self, shape: Option<&BorderShape>) -> Self {
+        match (&self, shape) {
+            (Self::Connectors, Some(shape)) => {
+                let (before, after) = shape.connectors();
+                Self::Brackets(before, after)
+            },
+            (Self::Connectors, None) => Self::None,
+            _ => self
+        }
+    }
 }
 
-/// The titles for a [TermBox]. Each [struct@Title] is placed
-/// independently.
+fn center_pad_len(width: usize, total_len: usize) -> usize {
+    (total_len / 2) - (width / 2) - 1
+}
+
+/// The titles for a [TermBox]. Each edge may hold any number of [titles](struct@Title).
+///
+/// Titles are placed inside the border of the box. On each edge, [Left](TitlePosition::Left) titles
+/// are placed from the lefthand corner and [Right](TitlePosition::Right) titles from the righthand
+/// corner, in the order they are listed, with one border glyph between titles in the same position.
+/// [Centered](TitlePosition::Centered) titles are grouped together in the center, and moved aside
+/// only as far as needed to keep clear of the others.
+///
+/// Titles never overlap: the box is made wide enough for every title on the edge, with at least
+/// one border glyph between groups of titles, unless its [title_overflow](TermBox::title_overflow)
+/// says otherwise. As with a single title, titles only keep their distance
+/// from the corners if the box's lines make it wide enough to. Titles may instead be placed at an
+/// exact offset or column with a [TitleAnchor].
+///
+/// Titles on the [left](Titles::left) and [right](Titles::right) sides run downwards, one character
+/// per row, and are placed the same way from the top and bottom corners. Blank rows are added below
+/// the box's lines if the side titles need more room, and the side is widened for any characters
+/// wider than its border.
+///
+/// See the [module-level docs](../../term_box/title/index.html) for more examples.
+///
+/// # Examples
 ///
-/// A term box may have up to two titles: one at the top, one at the bottom.
-/// Titles are placed inside the border of the box.
+/// ```
+/// use term_box::*;
 ///
-/// See the [module-level docs](../../term_box/title/index.html) for examples.
+/// let box_ = TermBox {
+///     titles: Titles::none()
+///         .with_top(Title("app", TitlePosition::Left))
+///         .with_top(Title("12:00", TitlePosition::Right))
+///         .with_top(Title("logs", TitlePosition::Centered)),
+///     lines: lines![ "x" ],
+///     ..TermBox::default()
+/// };
+///
+/// let output = "
+/// ┌app─logs─12:00┐
+/// │x             │
+/// └──────────────┘
+/// ";
+///
+/// assert_eq!(box_.into_string(), output.trim());
+/// ```
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Titles {
-    /// The title to display at the top of the box.
-    ///
-    /// Use [Title::empty] for no title.
-    pub top: Title,
-    /// The title to display at the bottom of the box.
-    ///
-    /// Use [Title::empty] for no title.
-    pub bottom: Title
+    /// The titles to display at the top of the box.
+    pub top: Vec<Title>,
+    /// The titles to display at the bottom of the box.
+    pub bottom: Vec<Title>,
+    /// The titles to display down the lefthand side of the box, one character per row.
+    pub left: Vec<Title>,
+    /// The titles to display down the righthand side of the box, one character per row.
+    pub right: Vec<Title>
 }
 
 impl Titles {
     /// Constructs [Titles] such that no titles will be displayed in the box.
     pub fn none() -> Self { Self::default() }
+
+    /// Adds a title to the top of the box and returns the titles.
+    pub fn with_top(mut self, title: Title) -> Self {
+        self.top.push(title);
+        self
+    }
+
+    /// Adds a title to the bottom of the box and returns the titles.
+    pub fn with_bottom(mut self, title: Title) -> Self {
+        self.bottom.push(title);
+        self
+    }
+
+    /// Adds a title to the lefthand side of the box and returns the titles.
+    pub fn with_left(mut self, title: Title) -> Self {
+        self.left.push(title);
+        self
+    }
+
+    /// Adds a title to the righthand side of the box and returns the titles.
+    pub fn with_right(mut self, title: Title) -> Self {
+        self.right.push(title);
+        self
+    }
+
+    /// Resolves the [connectors](TitleDecoration::Connectors) of the top and bottom titles against
+    /// the shapes of the edges they are on.
+    pub(crate) fn connected(mut self, style: &BorderStyle) -> Self {
+        connect(&mut self.top, style.side(Side::Top).as_ref());
+        connect(&mut self.bottom, style.side(Side::Bottom).as_ref());
+        self
+    }
+
+    /// Returns the narrowest the inside of the box can be while fitting all of its titles.
+    pub(crate) fn min_width(&self) -> usize {
+        cmp::max(min_width(&self.top), min_width(&self.bottom))
+    }
+
+    /// Returns the fewest rows the inside of the box can have while fitting all of its titles.
+    pub(crate) fn min_height(&self) -> usize {
+        cmp::max(min_len(&self.left, Title::height), min_len(&self.right, Title::height))
+    }
+
+    /// Returns the widths of the widest characters in the lefthand and righthand titles.
+    pub(crate) fn side_widths(&self) -> (usize, usize) {
+        let width = |titles: &[Title]| titles.iter()
+            .flat_map(|title| title.vertical_spans())
+            .map(|span| span.width())
+            .fold(0, cmp::max);
+        (width(&self.left), width(&self.right))
+    }
+}
+
+/// Describes what happens to the titles on the top or bottom edge of a [TermBox] when they are
+/// wider than the box's lines.
+///
+/// Titles widen the box up to its [max_width](TermBox::max_width) first, and only overflow past
+/// that, so a box without one is always made wide enough for its titles. Titles on the lefthand and
+/// righthand sides are not affected.
+///
+/// # Examples
+///
+/// ```
+/// use term_box::*;
+///
+/// let titled_box = |title_overflow| TermBox {
+///     titles: Titles::none().with_top(Title("/var/log/syslog", TitlePosition::Left)),
+///     lines: lines![ "12 lines" ],
+///     max_width: Some(10),
+///     title_overflow,
+///     ..TermBox::default()
+/// }.into_string();
+///
+/// assert_eq!(titled_box(TitleOverflow::Grow), "┌/var/log/syslog┐\n│12 lines       │\n└───────────────┘");
+/// assert_eq!(titled_box(TitleOverflow::ELLIPSIS), "┌/var/lo…┐\n│12 lines│\n└────────┘");
+/// assert_eq!(titled_box(TitleOverflow::Wrap), "┌────────┐\n│/var/log│\n│/syslog │\n│12 lines│\n└────────┘");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum TitleOverflow {
+    /// Make the box wide enough for its titles, even past its [max_width](TermBox::max_width).
+    #[default]
+    Grow,
+    /// Cut titles short so they fit, ending them with the given marker.
+    ///
+    /// The widest titles are cut first, so short titles on the same edge are kept whole for as long
+    /// as possible. Titles with no room at all are left out.
+    Truncate(Cow<'static, str>),
+    /// Move the titles into rows inside the box instead: below the top edge for top titles, and above
+    /// the bottom edge for bottom titles. Each title is [wrapped](super::Overflow::Wrap) to the width of
+    /// the box's lines and aligned according to its [TitlePosition].
+    Wrap
+}
+
+impl TitleOverflow {
+    /// Truncate titles that don't fit, ending them with an ellipsis (`…`).
+    pub const ELLIPSIS: TitleOverflow = TitleOverflow::Truncate(Cow::Borrowed("…"));
+
+    /// Fits the titles on the top or bottom edge into an edge `inner` columns long. Returns the
+    /// titles to display in the border, and the lines to display inside the box in their place,
+    /// wrapped to `text_width`.
+    pub(crate) fn fit(&self, titles: &[Title], inner: usize, text_width: usize) -> (Vec<Title>, Vec<Line>) {
+        if min_len(titles, Title::width) <= inner {
+            return (titles.to_vec(), Vec::new())
+        }
+
+        match self {
+            Self::Grow => (titles.to_vec(), Vec::new()),
+            Self::Truncate(marker) => (truncate(titles, inner, marker), Vec::new()),
+            Self::Wrap => {
+                let lines = titles.iter()
+                    .filter(|title| !title.is_empty())
+                    .flat_map(|title| overflow::wrap(&title.to_line(), text_width))
+                    .collect();
+                (Vec::new(), lines)
+            }
+        }
+    }
+}
+
+/// Cuts the widest titles short until they all fit in an edge `inner` columns long.
+fn truncate(titles: &[Title], inner: usize, marker: &str) -> Vec<Title> {
+    let titles: Vec<_> = titles.iter().filter(|title| !title.is_empty()).collect();
+    // Every title is separated from the next by one border glyph
+    let budget = inner.saturating_sub(titles.len().saturating_sub(1));
+
+    // The widest the text of any title may be for them all to fit, next to their decorations
+    let decorations = |title: &Title| title.width() - title.text_width();
+    let fits = |max: usize| titles.iter()
+        .map(|title| cmp::min(title.text_width(), max) + decorations(title))
+        .sum::<usize>() <= budget;
+    let max = (0..=budget).rev().find(|&max| fits(max)).unwrap_or(0);
+    if max == 0 {
+        return Vec::new()
+    }
+
+    titles.into_iter().map(|title| match title.text_width() > max {
+        false => title.clone(),
+        true => {
+            let text = overflow::truncate(&Line::from(title.text()), max, marker);
+            Title { text: CountedString::owned(text.to_string()), ..title.clone() }
+        }
+    }).collect()
+}
+
+/// Resolves the [connectors](TitleDecoration::Connectors) of the titles against the shape of the
+/// edge they are on, which is [None] if it isn't drawn.
+pub(crate) fn connect(titles: &mut [Title], shape: Option<&BorderShape>) {
+    for title in titles.iter_mut() {
+        title.decoration = std::mem::take(&mut title.decoration).connect(shape);
+    }
+}
+
+/// Returns the narrowest the inside of a top or bottom edge can be while fitting the titles.
+pub(crate) fn min_width(titles: &[Title]) -> usize {
+    min_len(titles, Title::width)
+}
+
+/// Measures the length of a title along its edge: [Title::width] or [Title::height].
+type Measure = fn(&Title) -> usize;
+
+/// The [titles](struct@Title) on one edge of the box that are in the same [TitlePosition].
+struct Group<'a> {
+    titles: Vec<&'a Title>,
+    measure: Measure,
+    len: usize
+}
+
+impl<'a> Group<'a> {
+    fn new(titles: &'a [Title], pos: TitlePosition, measure: Measure) -> Self {
+        let titles: Vec<_> = titles.iter()
+            .filter(|title| title.pos == pos && !title.is_empty() && title.anchored_len(measure(title)).is_none())
+            .collect();
+        let gaps = titles.len().saturating_sub(1);
+        let len = titles.iter().map(|title| measure(title)).sum::<usize>() + gaps;
+        Self { titles, measure, len }
+    }
+
+    fn is_empty(&self) -> bool { self.titles.is_empty() }
+
+    /// Places the titles one after another from `start`.
+    fn place(&self, start: usize, placed: &mut Vec<(usize, &'a Title)>) {
+        let mut pos = start;
+        for &title in self.titles.iter() {
+            placed.push((pos, title));
+            pos += (self.measure)(title) + 1;
+        }
+    }
+}
+
+fn groups(titles: &[Title], measure: Measure) -> [Group<'_>; 3] {
+    [TitlePosition::Left, TitlePosition::Centered, TitlePosition::Right].map(|pos| Group::new(titles, pos, measure))
+}
+
+/// Returns the shortest the inside of one edge of the box can be while fitting its titles.
+fn min_len(titles: &[Title], measure: Measure) -> usize {
+    let anchored = titles.iter()
+        .filter(|title| !title.is_empty())
+        .filter_map(|title| title.anchored_len(measure(title)))
+        .fold(0, cmp::max);
+    cmp::max(flow_len(titles, measure), anchored)
+}
+
+/// Returns the shortest the inside of one edge of the box can be while fitting the titles that
+/// aren't anchored.
+fn flow_len(titles: &[Title], measure: Measure) -> usize {
+    let groups = groups(titles, measure);
+    let gaps = groups.iter().filter(|group| !group.is_empty()).count().saturating_sub(1);
+    groups.iter().map(|group| group.len).sum::<usize>() + gaps
+}
+
+/// Returns the column at which each of the titles on the top or bottom edge starts, counting from
+/// the inside of the lefthand corner, in order. `total_len` is the full length of the edge, as for
+/// the box's lines.
+pub(crate) fn layout(titles: &[Title], total_len: usize) -> Vec<(usize, &Title)> {
+    layout_by(titles, total_len, Title::width)
+}
+
+/// Returns the character of a title to display in each of the `rows` rows of the lefthand or
+/// righthand side, if any.
+pub(crate) fn layout_vertical(titles: &[Title], rows: usize) -> Vec<Option<Span>> {
+    let mut cells = vec![None; rows];
+    for (start, title) in layout_by(titles, rows + TermBox::SIDES, Title::height) {
+        for (cell, span) in cells[start..].iter_mut().zip(title.vertical_spans()) {
+            *cell = Some(span);
+        }
+    }
+    cells
+}
+
+fn layout_by(titles: &[Title], total_len: usize, measure: Measure) -> Vec<(usize, &Title)> {
+    let inner = total_len - TermBox::SIDES;
+    let [left, centered, right] = groups(titles, measure);
+    let mut slack = inner.saturating_sub(flow_len(titles, measure));
+    // Titles only keep their distance from the corners if there's room to
+    let mut dist_from_corner = |group: &Group| match group.is_empty() {
+        true => 0,
+        false => {
+            let dist = cmp::min(DEFAULT_DIST_FROM_CORNER, slack);
+            slack -= dist;
+            dist
+        }
+    };
+
+    let left_start = dist_from_corner(&left);
+    let right_start = inner.saturating_sub(dist_from_corner(&right) + right.len);
+    // Anchored titles are placed first, so they take priority over the others
+    let mut placed: Vec<_> = titles.iter()
+        .filter(|title| !title.is_empty())
+        .filter_map(|title| title.anchored_start(inner, measure(title)).map(|start| (start, title)))
+        .collect();
+    left.place(left_start, &mut placed);
+    if !centered.is_empty() {
+        // Centered titles keep one border glyph between them and the others
+        let min_start = match left.is_empty() {
+            true => 0,
+            false => left_start + left.len + 1
+        };
+        let max_start = match right.is_empty() {
+            true => inner - centered.len,
+            false => right_start - centered.len - 1
+        };
+        let start = center_pad_len(centered.len, total_len).clamp(min_start, max_start);
+        centered.place(start, &mut placed);
+    }
+    right.place(right_start, &mut placed);
+
+    let mut kept: Vec<(usize, &Title)> = Vec::with_capacity(placed.len());
+    for (start, title) in placed {
+        let end = start + measure(title);
+        let overlaps = kept.iter().any(|&(kept_start, kept)| start < kept_start + measure(kept) && kept_start < end);
+        if end <= inner && !overlaps {
+            kept.push((start, title))
+        }
+    }
+    kept.sort_by_key(|&(col, _)| col);
+    kept
 }
 
 mod cons {
@@ -204,7 +736,11 @@ mod cons {
     pub fn Title(text: impl ToString, pos: TitlePosition) -> Title {
         Title {
             text: CountedString::owned(text.to_string()),
-            pos
+            pos,
+            style: AnsiStyle::default(),
+            decoration: TitleDecoration::None,
+            spacing: 0,
+            anchor: TitleAnchor::Flow
         }
     }
 }